@@ -2,8 +2,9 @@
 //!
 //! This example recreates the original Room MVP chat walkthrough using the
 //! latest runtime lifecycle APIs. The footer input is wired into the shared
-//! CLI bundle, scripted bot replies arrive on a timer, and status updates show
-//! focus changes alongside message hints.
+//! CLI bundle and submissions reach the timeline as `InputSubmitted` events,
+//! scripted bot replies arrive on a timer, and status updates show focus
+//! changes alongside message hints.
 //!
 //! ```bash
 //! cargo run --example chat_demo
@@ -15,10 +16,10 @@ use std::time::Duration;
 
 use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
 use room_mvp::{
-    default_cli_bundle, ensure_input_state, try_input_state, AnsiRenderer, CliDriver,
-    CliDriverError, Constraint, DefaultCliBundleConfig, Direction, EventFlow, LayoutError,
-    LayoutNode, LayoutTree, LegacyScreenStrategy, Rect, Result, RoomPlugin, RoomRuntime,
-    RuntimeConfig, RuntimeContext, RuntimeEvent, ScreenDefinition, ScreenManager, Size,
+    default_cli_bundle, AnsiRenderer, CliDriver, CliDriverError, Constraint,
+    DefaultCliBundleConfig, Direction, EventFlow, InputSubmitted, LayoutError, LayoutNode,
+    LayoutTree, LegacyScreenStrategy, Rect, Result, RoomPlugin, RoomRuntime, RuntimeConfig,
+    RuntimeContext, RuntimeEvent, ScreenDefinition, ScreenManager, Size,
 };
 
 const HEADER_ZONE: &str = "app:chat.header";
@@ -67,7 +68,6 @@ struct ChatPlugin {
     participants: Vec<&'static str>,
     messages: Vec<String>,
     scripted_replies: VecDeque<String>,
-    last_seen_submission: u64,
     bot_interval: Duration,
     bot_timer: Duration,
     status_overlay: Option<String>,
@@ -94,7 +94,6 @@ impl ChatPlugin {
                 "Alice: Focus changes now show up in the status footer.".to_string(),
                 "Bob: Timers and input sync run off the runtime tick.".to_string(),
             ]),
-            last_seen_submission: 0,
             bot_interval: Duration::from_secs(6),
             bot_timer: Duration::default(),
             status_overlay: None,
@@ -161,28 +160,18 @@ impl ChatPlugin {
         false
     }
 
-    fn process_input(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<bool> {
-        if let Some(shared) = try_input_state(ctx) {
-            if let Ok(state) = shared.read() {
-                if state.submission_count > self.last_seen_submission {
-                    self.last_seen_submission = state.submission_count;
-                    if let Some(last) = state.last_submission.as_ref() {
-                        let trimmed = last.trim();
-                        if matches!(trimmed, "/exit" | "/quit") {
-                            self.set_overlay("Exit requested");
-                            ctx.request_exit();
-                            return Ok(true);
-                        }
-                        if !trimmed.is_empty() {
-                            self.messages.push(format!("You: {}", last));
-                            self.set_overlay("Message sent");
-                            return Ok(true);
-                        }
-                    }
-                }
-            }
+    fn process_input(&mut self, ctx: &mut RuntimeContext<'_>, submitted: &InputSubmitted) {
+        let trimmed = submitted.text.trim();
+        if matches!(trimmed, "/exit" | "/quit") {
+            self.set_overlay("Exit requested");
+            ctx.request_exit();
+            return;
+        }
+        if !trimmed.is_empty() {
+            self.messages.push(format!("You: {}", submitted.text));
+            self.set_overlay("Message sent");
+            self.redraw(ctx);
         }
-        Ok(false)
     }
 }
 
@@ -199,7 +188,6 @@ impl RoomPlugin for ChatPlugin {
 
     fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
         self.ensure_initialized();
-        let _ = ensure_input_state(ctx);
         self.redraw(ctx);
         Ok(())
     }
@@ -235,7 +223,7 @@ impl RoomPlugin for ChatPlugin {
                 Ok(EventFlow::Continue)
             }
             RuntimeEvent::Tick { elapsed } => {
-                let mut state_changed = self.process_input(ctx)?;
+                let mut state_changed = false;
                 self.bot_timer += *elapsed;
                 self.overlay_timer += *elapsed;
 
@@ -257,6 +245,12 @@ impl RoomPlugin for ChatPlugin {
                 }
                 Ok(EventFlow::Continue)
             }
+            RuntimeEvent::Custom(_) => {
+                if let Some(submitted) = event.custom::<InputSubmitted>() {
+                    self.process_input(ctx, submitted);
+                }
+                Ok(EventFlow::Continue)
+            }
             RuntimeEvent::Key(_) | RuntimeEvent::Mouse(_) | RuntimeEvent::Paste(_) => {
                 Ok(EventFlow::Continue)
             }
@@ -280,7 +274,10 @@ impl RoomPlugin for ChatPlugin {
             | RuntimeEvent::FatalClose
             | RuntimeEvent::CursorMoved(_)
             | RuntimeEvent::CursorShown(_)
-            | RuntimeEvent::CursorHidden(_) => Ok(EventFlow::Continue),
+            | RuntimeEvent::CursorHidden(_)
+            | RuntimeEvent::ZoneMouse(_)
+            | RuntimeEvent::Action(_)
+            | RuntimeEvent::Timer { .. } => Ok(EventFlow::Continue),
         }
     }
 
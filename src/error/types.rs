@@ -18,6 +18,12 @@ pub enum LayoutError {
     Backend(String),
     #[error("grid layout error: {0}")]
     Grid(#[from] GridError),
+    #[error("invalid layout description: {0}")]
+    LayoutSpec(String),
+    #[error("invalid keymap: {0}")]
+    Keymap(String),
+    #[error("layout does not support edits")]
+    EditUnsupported,
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
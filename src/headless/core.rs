@@ -0,0 +1,427 @@
+use std::io::{self, Write};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::geometry::Size;
+use crate::render::Cell;
+use crate::style::Style;
+
+/// In-memory terminal that interprets the escape sequences emitted by
+/// [`AnsiRenderer`](crate::AnsiRenderer).
+///
+/// Pass it anywhere the runtime expects a `Write` handle (for example
+/// `RoomRuntime::run_scripted`) and assert on what a user would see instead of
+/// on raw escape bytes. Supported sequences: cursor positioning and movement
+/// (`CUP`, `CUU`/`CUD`/`CUF`/`CUB`, `CHA`), SGR, erase in display/line
+/// (`ED`/`EL`), scroll regions (`DECSTBM`, `SU`) and cursor visibility
+/// (`?25h`/`?25l`). Other sequences are parsed and ignored.
+///
+/// Every flush that follows new output records a snapshot of the screen text,
+/// so callers can inspect each frame the runtime committed.
+#[derive(Debug, Clone)]
+pub struct VirtualTerminal {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+    cursor: (u16, u16),
+    cursor_visible: bool,
+    style: Style,
+    /// Rows `top..bottom` that scroll, when narrower than the screen.
+    scroll_region: Option<(u16, u16)>,
+    parser: ParserState,
+    pending_utf8: Vec<u8>,
+    written_since_flush: bool,
+    frames: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+enum ParserState {
+    #[default]
+    Ground,
+    Escape,
+    Csi(String),
+    Osc {
+        saw_escape: bool,
+    },
+}
+
+impl VirtualTerminal {
+    pub fn new(size: Size) -> Self {
+        Self {
+            width: size.width,
+            height: size.height,
+            cells: vec![Cell::blank(); size.width as usize * size.height as usize],
+            cursor: (0, 0),
+            cursor_visible: true,
+            style: Style::default(),
+            scroll_region: None,
+            parser: ParserState::Ground,
+            pending_utf8: Vec::new(),
+            written_since_flush: false,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Resize the grid, keeping the cells that still fit.
+    pub fn resize(&mut self, size: Size) {
+        let mut cells = vec![Cell::blank(); size.width as usize * size.height as usize];
+        for row in 0..self.height.min(size.height) {
+            for col in 0..self.width.min(size.width) {
+                cells[row as usize * size.width as usize + col as usize] =
+                    self.cells[self.index(row, col)].clone();
+            }
+        }
+        self.width = size.width;
+        self.height = size.height;
+        self.cells = cells;
+        self.scroll_region = None;
+        self.cursor = (
+            self.cursor.0.min(size.height.saturating_sub(1)),
+            self.cursor.1.min(size.width.saturating_sub(1)),
+        );
+    }
+
+    pub fn cell(&self, row: u16, col: u16) -> Option<&Cell> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        self.cells.get(self.index(row, col))
+    }
+
+    pub fn style_at(&self, row: u16, col: u16) -> Option<Style> {
+        self.cell(row, col).map(|cell| cell.style)
+    }
+
+    /// Cursor position as `(row, col)`, zero-based.
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Text of one row with trailing blanks removed.
+    pub fn row_text(&self, row: u16) -> String {
+        if row >= self.height {
+            return String::new();
+        }
+        let mut text: String = (0..self.width)
+            .map(|col| self.cells[self.index(row, col)].symbol.as_str())
+            .collect();
+        text.truncate(text.trim_end().len());
+        text
+    }
+
+    /// Whole screen as text, one line per row, trailing blanks removed.
+    pub fn screen_text(&self) -> String {
+        (0..self.height)
+            .map(|row| self.row_text(row))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Screen snapshots recorded at each flush that followed new output.
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn take_frames(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.frames)
+    }
+
+    fn index(&self, row: u16, col: u16) -> usize {
+        row as usize * self.width as usize + col as usize
+    }
+
+    fn feed(&mut self, text: &str) {
+        for ch in text.chars() {
+            let state = std::mem::take(&mut self.parser);
+            self.parser = match state {
+                ParserState::Ground => {
+                    if ch == '\x1b' {
+                        ParserState::Escape
+                    } else {
+                        self.print(ch);
+                        ParserState::Ground
+                    }
+                }
+                ParserState::Escape => match ch {
+                    '[' => ParserState::Csi(String::new()),
+                    ']' => ParserState::Osc { saw_escape: false },
+                    _ => ParserState::Ground,
+                },
+                ParserState::Csi(mut params) => {
+                    if ('\x40'..='\x7e').contains(&ch) {
+                        self.dispatch_csi(&params, ch);
+                        ParserState::Ground
+                    } else {
+                        params.push(ch);
+                        ParserState::Csi(params)
+                    }
+                }
+                ParserState::Osc { saw_escape } => match ch {
+                    '\x07' => ParserState::Ground,
+                    '\\' if saw_escape => ParserState::Ground,
+                    '\x1b' => ParserState::Osc { saw_escape: true },
+                    _ => ParserState::Osc { saw_escape: false },
+                },
+            };
+        }
+    }
+
+    fn dispatch_csi(&mut self, params: &str, action: char) {
+        if let Some(private) = params.strip_prefix('?') {
+            if private.split(';').any(|mode| mode == "25") {
+                match action {
+                    'h' => self.cursor_visible = true,
+                    'l' => self.cursor_visible = false,
+                    _ => {}
+                }
+            }
+            return;
+        }
+
+        let args: Vec<u16> = params
+            .split(';')
+            .map(|arg| arg.parse().unwrap_or(0))
+            .collect();
+        let arg = |idx: usize, default: u16| match args.get(idx) {
+            Some(0) | None => default,
+            Some(value) => *value,
+        };
+        let (row, col) = self.cursor;
+        let max_row = self.height.saturating_sub(1);
+        let max_col = self.width.saturating_sub(1);
+
+        match action {
+            'H' | 'f' => {
+                self.cursor = (
+                    (arg(0, 1) - 1).min(max_row),
+                    (arg(1, 1) - 1).min(max_col),
+                );
+            }
+            'A' => self.cursor.0 = row.saturating_sub(arg(0, 1)),
+            'B' => self.cursor.0 = row.saturating_add(arg(0, 1)).min(max_row),
+            'C' => self.cursor.1 = col.saturating_add(arg(0, 1)).min(max_col),
+            'D' => self.cursor.1 = col.saturating_sub(arg(0, 1)),
+            'G' => self.cursor.1 = (arg(0, 1) - 1).min(max_col),
+            'J' => match args.first().copied().unwrap_or(0) {
+                0 => self.erase(self.index(row, col).min(self.cells.len()), self.cells.len()),
+                1 => self.erase(0, (self.index(row, col) + 1).min(self.cells.len())),
+                _ => self.erase(0, self.cells.len()),
+            },
+            'K' if row < self.height => {
+                let line_start = self.index(row, 0);
+                let line_end = line_start + self.width as usize;
+                let cursor = self.index(row, col.min(self.width));
+                match args.first().copied().unwrap_or(0) {
+                    0 => self.erase(cursor, line_end),
+                    1 => self.erase(line_start, (cursor + 1).min(line_end)),
+                    _ => self.erase(line_start, line_end),
+                }
+            }
+            'm' => self.style.apply_sgr(params),
+            'r' => {
+                let top = arg(0, 1) - 1;
+                let bottom = arg(1, self.height).min(self.height);
+                self.scroll_region = (top + 1 < bottom && (top, bottom) != (0, self.height))
+                    .then_some((top, bottom));
+                self.cursor = (0, 0);
+            }
+            'S' => self.scroll_up(arg(0, 1)),
+            _ => {}
+        }
+    }
+
+    fn erase(&mut self, from: usize, to: usize) {
+        self.cells[from..to]
+            .iter_mut()
+            .for_each(|cell| *cell = Cell::blank());
+    }
+
+    fn print(&mut self, ch: char) {
+        match ch {
+            '\n' => {
+                self.line_feed();
+                return;
+            }
+            '\r' => {
+                self.cursor.1 = 0;
+                return;
+            }
+            '\x08' => {
+                self.cursor.1 = self.cursor.1.saturating_sub(1);
+                return;
+            }
+            '\t' => {
+                let next = (self.cursor.1 / 8 + 1) * 8;
+                self.cursor.1 = next.min(self.width.saturating_sub(1));
+                return;
+            }
+            _ => {}
+        }
+
+        let Some(ch_width) = ch.width().map(|w| w as u16) else {
+            return;
+        };
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+
+        if ch_width == 0 {
+            let (row, col) = self.cursor;
+            if col > 0 {
+                // A wide glyph on the last column can leave the cursor past it.
+                let mut prev = (col - 1).min(self.width - 1);
+                if prev > 0 && self.cells[self.index(row, prev)].is_continuation() {
+                    prev -= 1;
+                }
+                let idx = self.index(row, prev);
+                self.cells[idx].symbol.push(ch);
+            }
+            return;
+        }
+
+        if self.cursor.1 + ch_width > self.width {
+            self.cursor.1 = 0;
+            self.line_feed();
+        }
+
+        let (row, col) = self.cursor;
+        let idx = self.index(row, col);
+        self.cells[idx] = Cell::new(ch, self.style);
+        if ch_width == 2 && col + 1 < self.width {
+            self.cells[idx + 1] = Cell::continuation(self.style);
+        }
+        self.cursor.1 = col + ch_width;
+    }
+
+    fn line_feed(&mut self) {
+        if self.height == 0 || self.width == 0 {
+            return;
+        }
+        let bottom = self.scroll_region.map_or(self.height, |(_, bottom)| bottom);
+        if self.cursor.0 + 1 == bottom {
+            self.scroll_up(1);
+        } else if self.cursor.0 + 1 < self.height {
+            self.cursor.0 += 1;
+        }
+    }
+
+    /// Scroll the scroll region (the whole screen by default) up by `lines`.
+    fn scroll_up(&mut self, lines: u16) {
+        let (top, bottom) = self.scroll_region.unwrap_or((0, self.height));
+        let width = self.width as usize;
+        let start = top as usize * width;
+        let end = bottom as usize * width;
+        let shift = (lines as usize * width).min(end - start);
+        self.cells[start..end].rotate_left(shift);
+        self.erase(end - shift, end);
+    }
+}
+
+impl Write for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending_utf8.extend_from_slice(buf);
+        let bytes = std::mem::take(&mut self.pending_utf8);
+        let mut rest = bytes.as_slice();
+
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(text) => {
+                    self.feed(text);
+                    break;
+                }
+                Err(err) => {
+                    let (valid, after) = rest.split_at(err.valid_up_to());
+                    self.feed(std::str::from_utf8(valid).expect("validated prefix"));
+                    match err.error_len() {
+                        Some(len) => {
+                            self.feed("\u{fffd}");
+                            rest = &after[len..];
+                        }
+                        None => {
+                            // Incomplete sequence: wait for the next write.
+                            self.pending_utf8 = after.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        if !buf.is_empty() {
+            self.written_since_flush = true;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.written_since_flush {
+            self.written_since_flush = false;
+            self.frames.push(self.screen_text());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn interprets_cursor_and_style_sequences() {
+        let mut term = VirtualTerminal::new(Size::new(8, 3));
+        write!(term, "\x1b[2;3H\x1b[1;31mhi\x1b[0m!\x1b[?25l").unwrap();
+
+        assert_eq!(term.row_text(1), "  hi!");
+        assert_eq!(term.cursor(), (1, 5));
+        assert!(!term.cursor_visible());
+        assert_eq!(
+            term.style_at(1, 2),
+            Some(Style::new().fg(Color::Red).bold())
+        );
+        assert_eq!(term.style_at(1, 4), Some(Style::default()));
+
+        write!(term, "\x1b[2J").unwrap();
+        assert_eq!(term.screen_text(), "\n\n");
+    }
+
+    #[test]
+    fn buffers_split_utf8_and_records_frames() {
+        let mut term = VirtualTerminal::new(Size::new(4, 1));
+        let bytes = "é!".as_bytes();
+        term.write_all(&bytes[..1]).unwrap();
+        term.write_all(&bytes[1..]).unwrap();
+        term.flush().unwrap();
+        term.flush().unwrap();
+
+        assert_eq!(term.row_text(0), "é!");
+        assert_eq!(term.frames(), &["é!".to_string()]);
+    }
+
+    #[test]
+    fn combining_marks_attach_to_wide_glyphs_on_the_last_column() {
+        let mut term = VirtualTerminal::new(Size::new(1, 1));
+        write!(term, "界\u{301}").unwrap();
+        assert_eq!(term.row_text(0), "界\u{301}");
+
+        let mut term = VirtualTerminal::new(Size::new(3, 1));
+        write!(term, "a界\u{301}").unwrap();
+        assert_eq!(term.row_text(0), "a界\u{301}");
+    }
+
+    #[test]
+    fn zero_sized_terminals_ignore_output() {
+        let mut term = VirtualTerminal::new(Size::new(5, 0));
+        write!(term, "ab\ncd\r\n").unwrap();
+        term.flush().unwrap();
+        assert_eq!(term.screen_text(), "");
+    }
+}
@@ -0,0 +1,5 @@
+//! Headless terminal backend orchestrator for deterministic frame capture.
+
+mod core;
+
+pub use core::VirtualTerminal;
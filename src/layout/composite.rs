@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::geometry::{Rect, Size};
+
+use super::{Layout, LayoutEdit, LayoutWarning};
+
+/// Separator between a subdivided zone and the zones of its nested layout.
+pub const ZONE_SEPARATOR: char = '/';
+
+/// Layout whose zones can be subdivided by further layouts.
+///
+/// A nested layout is solved inside the rect of the zone it replaces and its
+/// zones are namespaced under it, so a `LayoutTree` nested in grid zone `main`
+/// yields `main/toolbar` and `main/editor`. The subdivided zone itself is not
+/// part of the solved layout. Nested layouts may be composites too, and
+/// intrinsic sizes reported for `main/toolbar` reach the nested layout as
+/// `toolbar`. Edits apply to the root layout.
+pub struct CompositeLayout {
+    root: Box<dyn Layout>,
+    nested: Vec<(String, Box<dyn Layout>)>,
+}
+
+impl CompositeLayout {
+    pub fn new(root: impl Layout + 'static) -> Self {
+        Self {
+            root: Box::new(root),
+            nested: Vec::new(),
+        }
+    }
+
+    /// Subdivide `zone` of the root layout with `layout`, replacing any layout
+    /// nested there before.
+    pub fn nest(mut self, zone: impl Into<String>, layout: impl Layout + 'static) -> Self {
+        let zone = zone.into();
+        self.nested.retain(|(existing, _)| *existing != zone);
+        self.nested.push((zone, Box::new(layout)));
+        self
+    }
+
+    fn nested_intrinsic(zone: &str, intrinsic: &HashMap<String, Size>) -> HashMap<String, Size> {
+        intrinsic
+            .iter()
+            .filter_map(|(id, size)| {
+                let inner = id.strip_prefix(zone)?.strip_prefix(ZONE_SEPARATOR)?;
+                Some((inner.to_string(), *size))
+            })
+            .collect()
+    }
+}
+
+fn namespaced(zone: &str, id: &str) -> String {
+    format!("{zone}{ZONE_SEPARATOR}{id}")
+}
+
+impl Layout for CompositeLayout {
+    fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
+        self.solve_with_intrinsic(size, &HashMap::new())
+    }
+
+    fn solve_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Result<HashMap<String, Rect>> {
+        let mut rects = self.root.solve_with_intrinsic(size, intrinsic)?;
+        for (zone, layout) in &self.nested {
+            let Some(parent) = rects.remove(zone) else {
+                continue;
+            };
+            let inner = layout.solve_with_intrinsic(
+                Size::new(parent.width, parent.height),
+                &Self::nested_intrinsic(zone, intrinsic),
+            )?;
+            rects.extend(inner.into_iter().map(|(id, rect)| {
+                let rect = Rect::new(
+                    parent.x + rect.x,
+                    parent.y + rect.y,
+                    rect.width,
+                    rect.height,
+                );
+                (namespaced(zone, &id), rect)
+            }));
+        }
+        Ok(rects)
+    }
+
+    fn describe(&self, size: Size) -> HashMap<String, String> {
+        self.describe_with_intrinsic(size, &HashMap::new())
+    }
+
+    fn describe_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> HashMap<String, String> {
+        let mut rules = self.root.describe_with_intrinsic(size, intrinsic);
+        let Ok(rects) = self.root.solve_with_intrinsic(size, intrinsic) else {
+            return rules;
+        };
+        for (zone, layout) in &self.nested {
+            let Some(parent) = rects.get(zone) else {
+                continue;
+            };
+            let inner = layout.describe_with_intrinsic(
+                Size::new(parent.width, parent.height),
+                &Self::nested_intrinsic(zone, intrinsic),
+            );
+            rules.extend(
+                inner
+                    .into_iter()
+                    .map(|(id, rule)| (namespaced(zone, &id), rule)),
+            );
+        }
+        rules
+    }
+
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        self.diagnose_with_intrinsic(size, &HashMap::new())
+    }
+
+    fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Vec<LayoutWarning> {
+        let mut warnings = self.root.diagnose_with_intrinsic(size, intrinsic);
+        let Ok(rects) = self.root.solve_with_intrinsic(size, intrinsic) else {
+            return warnings;
+        };
+        for (zone, layout) in &self.nested {
+            let Some(parent) = rects.get(zone) else {
+                continue;
+            };
+            let inner = layout.diagnose_with_intrinsic(
+                Size::new(parent.width, parent.height),
+                &Self::nested_intrinsic(zone, intrinsic),
+            );
+            warnings.extend(
+                inner
+                    .into_iter()
+                    .map(|warning| warning.nested(zone, parent)),
+            );
+        }
+        warnings
+    }
+
+    fn apply_edit(&mut self, edit: LayoutEdit) -> Result<()> {
+        self.root.apply_edit(edit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{
+        Constraint, Direction, GridArea, GridLayout, GridSize, LayoutNode, LayoutTree,
+    };
+
+    fn toolbar() -> LayoutTree {
+        LayoutTree::new(LayoutNode::container(
+            "root",
+            Direction::Column,
+            vec![Constraint::Fixed(1), Constraint::Flex(1)],
+            vec![LayoutNode::leaf("toolbar"), LayoutNode::leaf("editor")],
+        ))
+    }
+
+    #[test]
+    fn nested_zones_are_offset_and_namespaced() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(10))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("nav", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+
+        let status = GridLayout::from_template(
+            "left right",
+            vec![GridSize::flex(1), GridSize::flex(1)],
+            vec![GridSize::flex(1)],
+        )
+        .unwrap();
+        let editor = CompositeLayout::new(toolbar()).nest("editor", status);
+        let layout = CompositeLayout::new(grid).nest("main", editor);
+
+        let rects = layout.solve(Size::new(30, 6)).unwrap();
+        assert!(!rects.contains_key("main"));
+        assert_eq!(rects["nav"], Rect::new(0, 0, 10, 6));
+        assert_eq!(rects["main/root"], Rect::new(10, 0, 20, 6));
+        assert_eq!(rects["main/toolbar"], Rect::new(10, 0, 20, 1));
+        assert_eq!(rects["main/editor/left"], Rect::new(10, 1, 10, 5));
+        assert_eq!(rects["main/editor/right"], Rect::new(20, 1, 10, 5));
+        assert_eq!(
+            layout.describe(Size::new(30, 6))["main/toolbar"],
+            "Fixed(1)"
+        );
+    }
+
+    #[test]
+    fn nested_diagnostics_are_scoped_and_use_intrinsic_sizes() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Auto)
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("side", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+
+        let mut panel = GridLayout::new();
+        panel
+            .add_col(GridSize::Fixed(6))
+            .add_row(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        panel.place("list", GridArea::cell(0, 0)).unwrap();
+        let layout = CompositeLayout::new(grid).nest("side", panel);
+
+        let size = Size::new(30, 6);
+        let intrinsic = HashMap::from([("side".to_string(), Size::new(8, 1))]);
+        assert_eq!(
+            layout.diagnose_with_intrinsic(size, &intrinsic),
+            vec![LayoutWarning::UnusedTrack {
+                scope: "side/rows".into(),
+                axis: "row",
+                index: 1,
+            }]
+        );
+        // Without the intrinsic width the `Auto` column is too narrow.
+        assert!(
+            layout
+                .diagnose(size)
+                .contains(&LayoutWarning::FixedOverflow {
+                    scope: "side/columns".into(),
+                    required: 6,
+                    available: 1,
+                })
+        );
+    }
+}
@@ -1,17 +1,24 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
 
 use crate::error::{LayoutError, Result};
 use crate::geometry::{Rect, Size};
 
+use super::diagnostics::{LayoutWarning, collapsed_zones};
+
 /// Layout direction for a container.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Direction {
+    #[default]
     Row,
     Column,
 }
 
 /// Space distribution rules for child nodes.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Constraint {
     Fixed(u16),
     Percent(u8),
@@ -24,13 +31,20 @@ pub enum Constraint {
 pub type NodeId = String;
 
 /// Layout node representation (container or leaf).
-#[derive(Debug, Clone)]
+/// Everything but `id` is optional when deserializing, so leaves are just
+/// `{"id": "app:body"}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LayoutNode {
     pub id: NodeId,
+    #[serde(default)]
     pub direction: Direction,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub constraints: Vec<Constraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<LayoutNode>,
+    #[serde(default)]
     pub gap: u16,
+    #[serde(default)]
     pub padding: u16,
 }
 
@@ -78,7 +92,7 @@ impl LayoutNode {
 }
 
 /// Layout tree orchestrator.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LayoutTree {
     pub root: LayoutNode,
 }
@@ -103,6 +117,39 @@ impl LayoutTree {
         Ok(rects)
     }
 
+    /// Sizing rule of every node as set by its parent, e.g. `Fixed(3)`.
+    /// The root is reported as `root`.
+    pub fn describe(&self) -> HashMap<NodeId, String> {
+        let mut rules = HashMap::new();
+        rules.insert(self.root.id.clone(), "root".to_string());
+        describe_children(&self.root, &mut rules);
+        rules
+    }
+
+    /// Report duplicate ids, containers whose fixed/minimum or percent
+    /// constraints do not fit, and zones that solve to zero size at `size`.
+    pub fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        let mut warnings = Vec::new();
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            if !seen.insert(node.id.as_str()) && reported.insert(node.id.as_str()) {
+                warnings.push(LayoutWarning::DuplicateId {
+                    id: node.id.clone(),
+                });
+            }
+            stack.extend(node.children.iter().rev());
+        }
+
+        let Ok(rects) = self.solve(size) else {
+            return warnings;
+        };
+        diagnose_node(&self.root, &rects, &mut warnings);
+        warnings.extend(collapsed_zones(&rects));
+        warnings
+    }
+
     fn solve_node(
         &self,
         node: &LayoutNode,
@@ -169,6 +216,69 @@ impl LayoutTree {
     }
 }
 
+fn diagnose_node(
+    node: &LayoutNode,
+    rects: &HashMap<NodeId, Rect>,
+    warnings: &mut Vec<LayoutWarning>,
+) {
+    if node.children.is_empty() {
+        return;
+    }
+
+    if let Some(rect) = rects.get(&node.id) {
+        let axis_length = match node.direction {
+            Direction::Row => rect.width,
+            Direction::Column => rect.height,
+        };
+        let gap_total = node
+            .gap
+            .saturating_mul(node.children.len().saturating_sub(1) as u16);
+        let available = axis_length
+            .saturating_sub(node.padding.saturating_mul(2))
+            .saturating_sub(gap_total);
+
+        let constraints = node.constraints.iter().take(node.children.len());
+        let mut required = 0u32;
+        let mut percent = 0u32;
+        for constraint in constraints {
+            match constraint {
+                Constraint::Fixed(value) | Constraint::Min(value) => required += *value as u32,
+                Constraint::Percent(value) => percent += *value as u32,
+                Constraint::Max(_) | Constraint::Flex(_) => {}
+            }
+        }
+        if required > available as u32 {
+            warnings.push(LayoutWarning::FixedOverflow {
+                scope: node.id.clone(),
+                required,
+                available,
+            });
+        }
+        if percent > 100 {
+            warnings.push(LayoutWarning::PercentOverflow {
+                scope: node.id.clone(),
+                total: percent,
+            });
+        }
+    }
+
+    for child in &node.children {
+        diagnose_node(child, rects, warnings);
+    }
+}
+
+fn describe_children(node: &LayoutNode, rules: &mut HashMap<NodeId, String>) {
+    for (idx, child) in node.children.iter().enumerate() {
+        let constraint = node
+            .constraints
+            .get(idx)
+            .copied()
+            .unwrap_or(Constraint::Flex(1));
+        rules.insert(child.id.clone(), format!("{constraint:?}"));
+        describe_children(child, rules);
+    }
+}
+
 fn distribute(
     distributable: u16,
     child_count: usize,
@@ -432,4 +542,48 @@ mod tests {
         assert_eq!(middle.y, top.bottom() + 1);
         assert_eq!(bottom.y, middle.bottom() + 1);
     }
+
+    #[test]
+    fn diagnose_reports_overflow_duplicates_and_collapsed_zones() {
+        let tree = LayoutTree::new(LayoutNode::container(
+            "root",
+            Direction::Row,
+            vec![
+                Constraint::Fixed(30),
+                Constraint::Fixed(30),
+                Constraint::Flex(1),
+            ],
+            vec![
+                LayoutNode::container(
+                    "left",
+                    Direction::Column,
+                    vec![Constraint::Percent(60), Constraint::Percent(60)],
+                    vec![LayoutNode::leaf("a"), LayoutNode::leaf("b")],
+                ),
+                LayoutNode::leaf("right"),
+                LayoutNode::leaf("a"),
+            ],
+        ));
+
+        let warnings = tree.diagnose(Size::new(50, 10));
+        assert_eq!(
+            warnings,
+            vec![
+                LayoutWarning::DuplicateId { id: "a".into() },
+                LayoutWarning::FixedOverflow {
+                    scope: "root".into(),
+                    required: 60,
+                    available: 50,
+                },
+                LayoutWarning::PercentOverflow {
+                    scope: "left".into(),
+                    total: 120,
+                },
+                LayoutWarning::ZoneCollapsed {
+                    zone: "a".into(),
+                    rect: Rect::new(60, 0, 0, 10),
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::geometry::Rect;
+
+/// Problem found by [`Layout::diagnose`](super::Layout::diagnose). Warnings
+/// never stop a layout from solving; they point at rules the solver had to
+/// bend or that have no effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutWarning {
+    /// A zone was solved to zero width or height.
+    ZoneCollapsed { zone: String, rect: Rect },
+    /// Fixed and minimum sizes in `scope` need more cells than it has.
+    FixedOverflow {
+        scope: String,
+        required: u32,
+        available: u16,
+    },
+    /// Percent sizes in `scope` add up to more than 100.
+    PercentOverflow { scope: String, total: u32 },
+    /// Several tree nodes share an id; only the last one keeps its rect.
+    DuplicateId { id: String },
+    /// A grid track in `scope` that no zone is placed in.
+    UnusedTrack {
+        scope: String,
+        axis: &'static str,
+        index: usize,
+    },
+}
+
+impl fmt::Display for LayoutWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutWarning::ZoneCollapsed { zone, rect } => {
+                write!(
+                    f,
+                    "zone `{zone}` collapsed to {}x{}",
+                    rect.width, rect.height
+                )
+            }
+            LayoutWarning::FixedOverflow {
+                scope,
+                required,
+                available,
+            } => write!(
+                f,
+                "`{scope}` needs {required} cells for fixed sizes but has {available}"
+            ),
+            LayoutWarning::PercentOverflow { scope, total } => {
+                write!(f, "`{scope}` percentages add up to {total}%")
+            }
+            LayoutWarning::DuplicateId { id } => write!(f, "node id `{id}` is used more than once"),
+            LayoutWarning::UnusedTrack { scope, axis, index } => {
+                write!(f, "`{scope}` {axis} {index} holds no zone")
+            }
+        }
+    }
+}
+
+impl LayoutWarning {
+    /// Re-home a warning from a layout nested in `zone` at `parent`, so ids
+    /// and rects match the composite layout.
+    pub(crate) fn nested(self, zone: &str, parent: &Rect) -> Self {
+        let scoped = |id: String| format!("{zone}{}{id}", super::composite::ZONE_SEPARATOR);
+        match self {
+            LayoutWarning::ZoneCollapsed { zone: id, rect } => LayoutWarning::ZoneCollapsed {
+                zone: scoped(id),
+                rect: Rect::new(
+                    parent.x + rect.x,
+                    parent.y + rect.y,
+                    rect.width,
+                    rect.height,
+                ),
+            },
+            LayoutWarning::FixedOverflow {
+                scope,
+                required,
+                available,
+            } => LayoutWarning::FixedOverflow {
+                scope: scoped(scope),
+                required,
+                available,
+            },
+            LayoutWarning::PercentOverflow { scope, total } => LayoutWarning::PercentOverflow {
+                scope: scoped(scope),
+                total,
+            },
+            LayoutWarning::DuplicateId { id } => LayoutWarning::DuplicateId { id: scoped(id) },
+            LayoutWarning::UnusedTrack { scope, axis, index } => LayoutWarning::UnusedTrack {
+                scope: scoped(scope),
+                axis,
+                index,
+            },
+        }
+    }
+}
+
+/// Zones solved to zero width or height, ordered by id.
+pub(crate) fn collapsed_zones(rects: &HashMap<String, Rect>) -> Vec<LayoutWarning> {
+    let mut collapsed: Vec<(&String, &Rect)> = rects
+        .iter()
+        .filter(|(_, rect)| rect.width == 0 || rect.height == 0)
+        .collect();
+    collapsed.sort_by_key(|(zone, _)| *zone);
+    collapsed
+        .into_iter()
+        .map(|(zone, rect)| LayoutWarning::ZoneCollapsed {
+            zone: zone.clone(),
+            rect: *rect,
+        })
+        .collect()
+}
@@ -0,0 +1,39 @@
+use super::{GridArea, GridSize, ZoneId};
+
+/// Change to a running layout, queued with `RuntimeContext::edit_layout` and
+/// applied before the next frame. Only grid layouts accept edits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutEdit {
+    /// Place a zone, or move it if it is already placed.
+    Place {
+        zone: ZoneId,
+        area: GridArea,
+    },
+    /// Take a zone out of the layout; its content is kept while hidden.
+    Remove {
+        zone: ZoneId,
+    },
+    AddCol(GridSize),
+    AddRow(GridSize),
+    SetCol {
+        index: usize,
+        size: GridSize,
+    },
+    SetRow {
+        index: usize,
+        size: GridSize,
+    },
+}
+
+impl LayoutEdit {
+    pub fn place(zone: impl Into<ZoneId>, area: GridArea) -> Self {
+        Self::Place {
+            zone: zone.into(),
+            area,
+        }
+    }
+
+    pub fn remove(zone: impl Into<ZoneId>) -> Self {
+        Self::Remove { zone: zone.into() }
+    }
+}
@@ -5,6 +5,7 @@
 //! - Define all rows and columns upfront (no nesting required)
 //! - Zone placement via GridArea (cell coordinates and spanning)
 //! - Automatic space distribution with Fixed, Flex, and Percent sizing
+//! - Content-sized tracks (Auto, Min, Max) and clamped flexible tracks (MinMax)
 //! - Fail-fast validation (bounds checking, overlap detection)
 //!
 //! # Example
@@ -28,9 +29,26 @@
 //! grid.place("main", GridArea::cell(1, 1))?;
 //! # Ok::<(), room_mvp::layout::grid::GridError>(())
 //! ```
+//!
+//! The same grid can be drawn as a template of named areas:
+//! ```no_run
+//! use room_mvp::layout::grid::{GridLayout, GridSize};
+//!
+//! let grid = GridLayout::from_template(
+//!     "header header header
+//!      sidebar main  side
+//!      footer footer footer",
+//!     [GridSize::Fixed(20), GridSize::flex(2), GridSize::flex(1)],
+//!     [GridSize::Fixed(3), GridSize::flex(1), GridSize::Fixed(2)],
+//! )?;
+//! # Ok::<(), room_mvp::layout::grid::GridError>(())
+//! ```
 
+use super::LayoutEdit;
+use super::diagnostics::{LayoutWarning, collapsed_zones};
 use crate::{Rect, Size};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::num::{NonZeroU16, NonZeroU8};
 use std::ops::Range;
 
@@ -56,6 +74,28 @@ pub enum GridError {
         zone1: ZoneId,
         zone2: ZoneId,
     },
+    EmptyTemplate,
+    RaggedTemplate {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    NonRectangularArea {
+        zone: ZoneId,
+    },
+    TrackCountMismatch {
+        axis: &'static str,
+        template: usize,
+        tracks: usize,
+    },
+    UnknownZone {
+        zone: ZoneId,
+    },
+    TrackOutOfRange {
+        axis: &'static str,
+        index: usize,
+        count: usize,
+    },
 }
 
 impl std::fmt::Display for GridError {
@@ -80,6 +120,34 @@ impl std::fmt::Display for GridError {
             GridError::OverlappingAreas { zone1, zone2 } => {
                 write!(f, "Zones '{}' and '{}' overlap", zone1, zone2)
             }
+            GridError::EmptyTemplate => write!(f, "Grid template has no cells"),
+            GridError::RaggedTemplate {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Grid template row {} has {} cells, expected {}",
+                row, found, expected
+            ),
+            GridError::NonRectangularArea { zone } => {
+                write!(f, "Template area '{}' is not a rectangle", zone)
+            }
+            GridError::TrackCountMismatch {
+                axis,
+                template,
+                tracks,
+            } => write!(
+                f,
+                "Grid template has {} {}, but {} track sizes were given",
+                template, axis, tracks
+            ),
+            GridError::UnknownZone { zone } => write!(f, "Zone '{}' is not in the grid", zone),
+            GridError::TrackOutOfRange { axis, index, count } => write!(
+                f,
+                "Grid {} track {} out of range (grid has {})",
+                axis, index, count
+            ),
         }
     }
 }
@@ -87,7 +155,11 @@ impl std::fmt::Display for GridError {
 impl std::error::Error for GridError {}
 
 /// Defines how a column or row should be sized
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Serialized in snake case: `{"fixed": 20}`, `{"flex": 2}`, `"auto"`,
+/// `{"min_max": [10, 30]}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GridSize {
     /// Fixed size in terminal cells
     Fixed(u16),
@@ -101,6 +173,19 @@ pub enum GridSize {
     /// If total percentages exceed 100%, they will be normalized proportionally
     /// If total is less than 100%, remaining space goes to Flex tracks
     Percent(NonZeroU8),
+
+    /// Sized to the largest intrinsic size reported by the zones placed in this
+    /// track alone (zones spanning several tracks are ignored)
+    Auto,
+
+    /// Like `Auto`, but never smaller than the given size
+    Min(u16),
+
+    /// Like `Auto`, but never larger than the given size
+    Max(u16),
+
+    /// Flexible track (weight 1, like `Flex(1)`) clamped to `min..=max`
+    MinMax(u16, u16),
 }
 
 impl GridSize {
@@ -120,10 +205,23 @@ impl GridSize {
         assert!(n > 0 && n <= 100, "Percent must be 1-100");
         Self::Percent(NonZeroU8::new(n).unwrap())
     }
+
+    /// Create MinMax variant
+    ///
+    /// # Panics
+    /// Panics if min is greater than max
+    pub fn min_max(min: u16, max: u16) -> Self {
+        assert!(min <= max, "MinMax min must not exceed max");
+        Self::MinMax(min, max)
+    }
+
+    fn is_flexible(&self) -> bool {
+        matches!(self, GridSize::Flex(_) | GridSize::MinMax(..))
+    }
 }
 
 /// Defines the placement of a zone within the grid
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GridArea {
     pub rows: Range<usize>,
     pub cols: Range<usize>,
@@ -183,6 +281,12 @@ impl GridArea {
 }
 
 /// Grid layout container with builder pattern
+///
+/// Deserialization goes through the same validation as [`GridLayout::place`]
+/// and [`GridLayout::from_template`]; a description may give explicit `areas`,
+/// a `template`, or both.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "GridSpec", into = "GridSpec")]
 pub struct GridLayout {
     cols: Vec<GridSize>,
     rows: Vec<GridSize>,
@@ -200,6 +304,73 @@ impl GridLayout {
         }
     }
 
+    /// Build a grid from an ASCII map of named areas plus the column and row
+    /// track sizes.
+    ///
+    /// Rows are separated by newlines or a whitespace-delimited `/` and may be
+    /// wrapped in quotes, so both `"a a / b c"` and CSS-style
+    /// `"\"a a\" / \"b c\""` work. Cells are whitespace-separated zone names
+    /// (which may themselves contain `/`, as in `main/editor`); `.` leaves a
+    /// cell empty. Every name must cover a single rectangle.
+    pub fn from_template(
+        template: &str,
+        cols: impl IntoIterator<Item = GridSize>,
+        rows: impl IntoIterator<Item = GridSize>,
+    ) -> Result<Self, GridError> {
+        let cells = parse_template(template)?;
+        let mut grid = Self::new();
+        grid.cols.extend(cols);
+        grid.rows.extend(rows);
+
+        let row_count = cells.len();
+        let col_count = cells[0].len();
+        if grid.cols.len() != col_count {
+            return Err(GridError::TrackCountMismatch {
+                axis: "columns",
+                template: col_count,
+                tracks: grid.cols.len(),
+            });
+        }
+        if grid.rows.len() != row_count {
+            return Err(GridError::TrackCountMismatch {
+                axis: "rows",
+                template: row_count,
+                tracks: grid.rows.len(),
+            });
+        }
+
+        // Bounding box of every name, in order of first appearance.
+        let mut areas: Vec<(&str, GridArea)> = Vec::new();
+        for (row, line) in cells.iter().enumerate() {
+            for (col, name) in line.iter().enumerate() {
+                if *name == "." {
+                    continue;
+                }
+                match areas.iter_mut().find(|(existing, _)| existing == name) {
+                    Some((_, area)) => {
+                        area.rows.start = area.rows.start.min(row);
+                        area.rows.end = area.rows.end.max(row + 1);
+                        area.cols.start = area.cols.start.min(col);
+                        area.cols.end = area.cols.end.max(col + 1);
+                    }
+                    None => areas.push((name, GridArea::cell(row, col))),
+                }
+            }
+        }
+
+        for (name, area) in areas {
+            let rectangular = cells[area.rows.clone()]
+                .iter()
+                .all(|line| line[area.cols.clone()].iter().all(|cell| *cell == name));
+            if !rectangular {
+                return Err(GridError::NonRectangularArea { zone: name.into() });
+            }
+            grid.place(name, area)?;
+        }
+
+        Ok(grid)
+    }
+
     pub fn add_col(&mut self, size: GridSize) -> &mut Self {
         self.cols.push(size);
         self
@@ -241,9 +412,196 @@ impl GridLayout {
         Ok(self)
     }
 
+    /// Area a zone is placed in.
+    pub fn area(&self, zone_id: &str) -> Option<&GridArea> {
+        self.areas.get(zone_id)
+    }
+
+    /// Take a zone out of the grid, returning the area it covered.
+    pub fn remove(&mut self, zone_id: &str) -> Option<GridArea> {
+        self.areas.remove(zone_id)
+    }
+
+    /// Move a placed zone to a new area, which may overlap its old one but no
+    /// other zone's.
+    pub fn move_zone(&mut self, zone_id: &str, area: GridArea) -> Result<&mut Self, GridError> {
+        let Some(previous) = self.areas.remove(zone_id) else {
+            return Err(GridError::UnknownZone {
+                zone: zone_id.to_string(),
+            });
+        };
+        match self.place(zone_id, area) {
+            Ok(_) => Ok(self),
+            Err(err) => {
+                self.areas.insert(zone_id.to_string(), previous);
+                Err(err)
+            }
+        }
+    }
+
+    /// Change the size of an existing column.
+    pub fn set_col(&mut self, index: usize, size: GridSize) -> Result<&mut Self, GridError> {
+        Self::set_track(&mut self.cols, "column", index, size)?;
+        Ok(self)
+    }
+
+    /// Change the size of an existing row.
+    pub fn set_row(&mut self, index: usize, size: GridSize) -> Result<&mut Self, GridError> {
+        Self::set_track(&mut self.rows, "row", index, size)?;
+        Ok(self)
+    }
+
+    fn set_track(
+        tracks: &mut [GridSize],
+        axis: &'static str,
+        index: usize,
+        size: GridSize,
+    ) -> Result<(), GridError> {
+        if index >= tracks.len() {
+            return Err(GridError::TrackOutOfRange {
+                axis,
+                index,
+                count: tracks.len(),
+            });
+        }
+        tracks[index] = size;
+        Ok(())
+    }
+
+    /// Apply a [`LayoutEdit`]. `Place` moves the zone when it is already placed.
+    pub fn apply_edit(&mut self, edit: LayoutEdit) -> Result<(), GridError> {
+        match edit {
+            LayoutEdit::Place { zone, area } if self.areas.contains_key(&zone) => {
+                self.move_zone(&zone, area)?;
+            }
+            LayoutEdit::Place { zone, area } => {
+                self.place(zone, area)?;
+            }
+            LayoutEdit::Remove { zone } => {
+                if self.remove(&zone).is_none() {
+                    return Err(GridError::UnknownZone { zone });
+                }
+            }
+            LayoutEdit::AddCol(size) => {
+                self.add_col(size);
+            }
+            LayoutEdit::AddRow(size) => {
+                self.add_row(size);
+            }
+            LayoutEdit::SetCol { index, size } => {
+                self.set_col(index, size)?;
+            }
+            LayoutEdit::SetRow { index, size } => {
+                self.set_row(index, size)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn solve(&self, total_size: Size) -> Result<HashMap<ZoneId, Rect>, GridError> {
-        let (col_offsets, col_sizes) = Self::solve_axis(&self.cols, total_size.width, self.gap);
-        let (row_offsets, row_sizes) = Self::solve_axis(&self.rows, total_size.height, self.gap);
+        self.solve_with_intrinsic(total_size, &HashMap::new())
+    }
+
+    /// Track sizes each zone spans, as `cols × rows`, e.g. `Fixed(20) × Flex(1)`.
+    pub fn describe(&self) -> HashMap<ZoneId, String> {
+        let tracks = |sizes: &[GridSize]| {
+            sizes
+                .iter()
+                .map(|size| format!("{size:?}"))
+                .collect::<Vec<_>>()
+                .join("+")
+        };
+        self.areas
+            .iter()
+            .map(|(zone, area)| {
+                let cols = tracks(&self.cols[area.cols.clone()]);
+                let rows = tracks(&self.rows[area.rows.clone()]);
+                (zone.clone(), format!("{cols} × {rows}"))
+            })
+            .collect()
+    }
+
+    /// Report tracks no zone is placed in, axes whose fixed/minimum or percent
+    /// sizes do not fit, and zones that solve to zero size at `size`.
+    pub fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        self.diagnose_with_intrinsic(size, &HashMap::new())
+    }
+
+    /// Like [`diagnose`](Self::diagnose), solving content-sized tracks with the
+    /// reported intrinsic sizes.
+    pub fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<ZoneId, Size>,
+    ) -> Vec<LayoutWarning> {
+        let mut warnings = Vec::new();
+        for (axis, scope, tracks, total) in [
+            ("column", "columns", &self.cols, size.width),
+            ("row", "rows", &self.rows, size.height),
+        ] {
+            for index in 0..tracks.len() {
+                let used = self.areas.values().any(|area| {
+                    let range = if axis == "column" {
+                        &area.cols
+                    } else {
+                        &area.rows
+                    };
+                    range.contains(&index)
+                });
+                if !used {
+                    warnings.push(LayoutWarning::UnusedTrack {
+                        scope: scope.to_string(),
+                        axis,
+                        index,
+                    });
+                }
+            }
+
+            let gaps = self.gap as u32 * tracks.len().saturating_sub(1) as u32;
+            let mut required = gaps;
+            let mut percent = 0u32;
+            for track in tracks {
+                match track {
+                    GridSize::Fixed(value) | GridSize::Min(value) | GridSize::MinMax(value, _) => {
+                        required += *value as u32
+                    }
+                    GridSize::Percent(value) => percent += value.get() as u32,
+                    _ => {}
+                }
+            }
+            if required > total as u32 {
+                warnings.push(LayoutWarning::FixedOverflow {
+                    scope: scope.to_string(),
+                    required,
+                    available: total,
+                });
+            }
+            if percent > 100 {
+                warnings.push(LayoutWarning::PercentOverflow {
+                    scope: scope.to_string(),
+                    total: percent,
+                });
+            }
+        }
+
+        if let Ok(rects) = self.solve_with_intrinsic(size, intrinsic) {
+            warnings.extend(collapsed_zones(&rects));
+        }
+        warnings
+    }
+
+    /// Solve using the intrinsic (content) sizes reported for zones, which
+    /// drive `Auto`, `Min` and `Max` tracks. Zones without a report count as 0.
+    pub fn solve_with_intrinsic(
+        &self,
+        total_size: Size,
+        intrinsic: &HashMap<ZoneId, Size>,
+    ) -> Result<HashMap<ZoneId, Rect>, GridError> {
+        let (col_content, row_content) = self.track_content(intrinsic);
+        let (col_offsets, col_sizes) =
+            Self::solve_axis(&self.cols, &col_content, total_size.width, self.gap);
+        let (row_offsets, row_sizes) =
+            Self::solve_axis(&self.rows, &row_content, total_size.height, self.gap);
 
         let mut result = HashMap::new();
 
@@ -273,7 +631,31 @@ impl GridLayout {
         Ok(result)
     }
 
-    fn solve_axis(tracks: &[GridSize], total: u16, gap: u16) -> (Vec<u16>, Vec<u16>) {
+    /// Largest intrinsic width per column and height per row, counting only
+    /// zones that sit in a single track on that axis.
+    fn track_content(&self, intrinsic: &HashMap<ZoneId, Size>) -> (Vec<u16>, Vec<u16>) {
+        let mut cols = vec![0u16; self.cols.len()];
+        let mut rows = vec![0u16; self.rows.len()];
+        for (zone_id, area) in &self.areas {
+            let Some(size) = intrinsic.get(zone_id) else {
+                continue;
+            };
+            if area.cols.len() == 1 {
+                cols[area.cols.start] = cols[area.cols.start].max(size.width);
+            }
+            if area.rows.len() == 1 {
+                rows[area.rows.start] = rows[area.rows.start].max(size.height);
+            }
+        }
+        (cols, rows)
+    }
+
+    fn solve_axis(
+        tracks: &[GridSize],
+        content: &[u16],
+        total: u16,
+        gap: u16,
+    ) -> (Vec<u16>, Vec<u16>) {
         if tracks.is_empty() {
             return (vec![0], vec![]);
         }
@@ -290,11 +672,25 @@ impl GridLayout {
         let mut track_sizes = vec![0u16; track_count];
         let mut remaining = available;
 
+        // Fixed and content-sized tracks are resolved before anything else.
+        let resolved: Vec<Option<u16>> = tracks
+            .iter()
+            .enumerate()
+            .map(|(i, track)| {
+                let content = content.get(i).copied().unwrap_or(0);
+                match track {
+                    GridSize::Fixed(size) => Some(*size),
+                    GridSize::Auto => Some(content),
+                    GridSize::Min(min) => Some(content.max(*min)),
+                    GridSize::Max(max) => Some(content.min(*max)),
+                    _ => None,
+                }
+            })
+            .collect();
+
         let mut fixed_sum = 0u16;
-        for track in tracks {
-            if let GridSize::Fixed(size) = track {
-                fixed_sum = fixed_sum.saturating_add(*size);
-            }
+        for size in resolved.iter().flatten() {
+            fixed_sum = fixed_sum.saturating_add(*size);
         }
         remaining = remaining.saturating_sub(fixed_sum);
 
@@ -322,19 +718,8 @@ impl GridLayout {
         }
         remaining = remaining.saturating_sub(percent_used);
 
-        let flex_sum: u32 = tracks
-            .iter()
-            .filter_map(|t| match t {
-                GridSize::Flex(f) => Some(f.get() as u32),
-                _ => None,
-            })
-            .sum();
-
         for (i, track) in tracks.iter().enumerate() {
             match track {
-                GridSize::Fixed(size) => {
-                    track_sizes[i] = *size;
-                }
                 GridSize::Percent(p) => {
                     let weight = if total_percent > 100 {
                         (p.get() as u32 * 100) / total_percent
@@ -344,15 +729,16 @@ impl GridLayout {
                     let size = (((available.saturating_sub(fixed_sum)) as u32 * weight) / 100) as u16;
                     track_sizes[i] = size;
                 }
-                GridSize::Flex(f) => {
-                    if flex_sum > 0 {
-                        let size = ((remaining as u32 * f.get() as u32) / flex_sum) as u16;
+                _ => {
+                    if let Some(size) = resolved[i] {
                         track_sizes[i] = size;
                     }
                 }
             }
         }
 
+        Self::distribute_flexible(tracks, remaining, &mut track_sizes);
+
         let mut remaining_for_min = available;
         for i in 0..track_sizes.len() {
             if track_sizes[i] == 0 && remaining_for_min > 0 {
@@ -368,7 +754,11 @@ impl GridLayout {
             if leftover == 0 {
                 break;
             }
-            if matches!(track, GridSize::Flex(_)) {
+            let below_max = match track {
+                GridSize::MinMax(_, max) => track_sizes[i] < *max,
+                _ => true,
+            };
+            if track.is_flexible() && below_max {
                 track_sizes[i] = track_sizes[i].saturating_add(1);
                 leftover = leftover.saturating_sub(1);
             }
@@ -404,6 +794,131 @@ impl GridLayout {
 
         (offsets, track_sizes)
     }
+
+    /// Share `pool` between Flex and MinMax tracks by weight. A MinMax track
+    /// whose share falls outside its bounds is pinned to the nearest bound and
+    /// the rest is shared again among the remaining tracks.
+    fn distribute_flexible(tracks: &[GridSize], mut pool: u16, track_sizes: &mut [u16]) {
+        let weight = |track: &GridSize| match track {
+            GridSize::Flex(f) => f.get() as u32,
+            _ => 1,
+        };
+        let mut active: Vec<usize> = (0..tracks.len())
+            .filter(|&i| tracks[i].is_flexible())
+            .collect();
+
+        loop {
+            let weight_sum: u32 = active.iter().map(|&i| weight(&tracks[i])).sum();
+            if weight_sum == 0 {
+                return;
+            }
+            let share_pool = pool;
+            let share = |i: usize| ((share_pool as u32 * weight(&tracks[i])) / weight_sum) as u16;
+
+            let mut pinned = false;
+            active.retain(|&i| {
+                if let GridSize::MinMax(min, max) = tracks[i] {
+                    let size = share(i).clamp(min, max.max(min));
+                    if size != share(i) {
+                        track_sizes[i] = size;
+                        pool = pool.saturating_sub(size);
+                        pinned = true;
+                        return false;
+                    }
+                }
+                true
+            });
+
+            if !pinned {
+                for &i in &active {
+                    track_sizes[i] = share(i);
+                }
+                return;
+            }
+        }
+    }
+}
+
+/// Serialized form of [`GridLayout`].
+#[derive(Serialize, Deserialize)]
+struct GridSpec {
+    cols: Vec<GridSize>,
+    rows: Vec<GridSize>,
+    #[serde(default)]
+    gap: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    areas: BTreeMap<ZoneId, GridArea>,
+}
+
+impl TryFrom<GridSpec> for GridLayout {
+    type Error = GridError;
+
+    fn try_from(spec: GridSpec) -> Result<Self, GridError> {
+        let mut grid = match &spec.template {
+            Some(template) => GridLayout::from_template(template, spec.cols, spec.rows)?,
+            None => {
+                let mut grid = GridLayout::new();
+                grid.cols = spec.cols;
+                grid.rows = spec.rows;
+                grid
+            }
+        };
+        grid.gap = spec.gap;
+        for (zone_id, area) in spec.areas {
+            grid.place(zone_id, area)?;
+        }
+        Ok(grid)
+    }
+}
+
+impl From<GridLayout> for GridSpec {
+    fn from(grid: GridLayout) -> Self {
+        Self {
+            cols: grid.cols,
+            rows: grid.rows,
+            gap: grid.gap,
+            template: None,
+            areas: grid.areas.into_iter().collect(),
+        }
+    }
+}
+
+/// Split a template into rows of cell names, checking every row has the same
+/// number of cells.
+fn parse_template(template: &str) -> Result<Vec<Vec<&str>>, GridError> {
+    let mut cells: Vec<Vec<&str>> = Vec::new();
+    for line in template.lines() {
+        let mut row = Vec::new();
+        for token in line.split_whitespace() {
+            if token == "/" {
+                cells.push(std::mem::take(&mut row));
+                continue;
+            }
+            let name = token.trim_matches('"');
+            if !name.is_empty() {
+                row.push(name);
+            }
+        }
+        cells.push(row);
+    }
+    cells.retain(|row| !row.is_empty());
+
+    let Some(first) = cells.first() else {
+        return Err(GridError::EmptyTemplate);
+    };
+    let expected = first.len();
+    for (row, line) in cells.iter().enumerate() {
+        if line.len() != expected {
+            return Err(GridError::RaggedTemplate {
+                row,
+                expected,
+                found: line.len(),
+            });
+        }
+    }
+    Ok(cells)
 }
 
 impl Default for GridLayout {
@@ -959,4 +1474,274 @@ mod tests {
             assert!(rect.x + rect.width <= 100, "Zone '{}' extends beyond terminal", zone);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_grid_layout_solve_auto_uses_intrinsic_size() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Auto)
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::Auto)
+            .add_row(GridSize::flex(1));
+
+        grid.place("menu", GridArea::cell(0, 0)).unwrap();
+        grid.place("title", GridArea::cell(0, 1)).unwrap();
+        grid.place("body", GridArea::span_cols(1, 0..2)).unwrap();
+
+        let mut intrinsic = HashMap::new();
+        intrinsic.insert("menu".to_string(), Size::new(12, 2));
+        intrinsic.insert("title".to_string(), Size::new(5, 3));
+        // Spanning zones do not size single tracks.
+        intrinsic.insert("body".to_string(), Size::new(90, 1));
+
+        let result = grid
+            .solve_with_intrinsic(Size::new(100, 20), &intrinsic)
+            .unwrap();
+        assert_eq!(result["menu"], Rect::new(0, 0, 12, 3));
+        assert_eq!(result["title"], Rect::new(12, 0, 88, 3));
+        assert_eq!(result["body"], Rect::new(0, 3, 100, 17));
+    }
+
+    #[test]
+    fn test_grid_layout_solve_min_and_max_clamp_content() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Max(30))
+            .add_col(GridSize::Min(10))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+
+        grid.place("sidebar", GridArea::cell(0, 0)).unwrap();
+        grid.place("tools", GridArea::cell(0, 1)).unwrap();
+        grid.place("main", GridArea::cell(0, 2)).unwrap();
+
+        let mut intrinsic = HashMap::new();
+        intrinsic.insert("sidebar".to_string(), Size::new(18, 1));
+        intrinsic.insert("tools".to_string(), Size::new(4, 1));
+        let result = grid
+            .solve_with_intrinsic(Size::new(100, 10), &intrinsic)
+            .unwrap();
+        assert_eq!(result["sidebar"].width, 18);
+        assert_eq!(result["tools"].width, 10);
+        assert_eq!(result["main"].width, 72);
+
+        intrinsic.insert("sidebar".to_string(), Size::new(45, 1));
+        let result = grid
+            .solve_with_intrinsic(Size::new(100, 10), &intrinsic)
+            .unwrap();
+        assert_eq!(result["sidebar"].width, 30);
+        assert_eq!(result["main"].width, 60);
+    }
+
+    #[test]
+    fn test_grid_layout_solve_min_max_clamps_flexible_share() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::min_max(10, 20))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+
+        grid.place("side", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+
+        let wide = grid.solve(Size::new(100, 5)).unwrap();
+        assert_eq!(wide["side"].width, 20);
+        assert_eq!(wide["main"].width, 80);
+
+        let medium = grid.solve(Size::new(30, 5)).unwrap();
+        assert_eq!(medium["side"].width, 15);
+        assert_eq!(medium["main"].width, 15);
+
+        let narrow = grid.solve(Size::new(16, 5)).unwrap();
+        assert_eq!(narrow["side"].width, 10);
+        assert_eq!(narrow["main"].width, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "MinMax min must not exceed max")]
+    fn test_grid_size_min_max_inverted_panics() {
+        GridSize::min_max(5, 2);
+    }
+
+    #[test]
+    fn test_grid_layout_from_template_derives_areas() {
+        let grid = GridLayout::from_template(
+            "\"header header header\" / \"nav main side\" / \"footer footer footer\"",
+            [GridSize::Fixed(10), GridSize::flex(1), GridSize::Fixed(10)],
+            [GridSize::Fixed(1), GridSize::flex(1), GridSize::Fixed(1)],
+        )
+        .unwrap();
+
+        let result = grid.solve(Size::new(40, 10)).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result["header"], Rect::new(0, 0, 40, 1));
+        assert_eq!(result["nav"], Rect::new(0, 1, 10, 8));
+        assert_eq!(result["main"], Rect::new(10, 1, 20, 8));
+        assert_eq!(result["side"], Rect::new(30, 1, 10, 8));
+        assert_eq!(result["footer"], Rect::new(0, 9, 40, 1));
+    }
+
+    #[test]
+    fn test_grid_layout_from_template_multiline_with_empty_cells() {
+        let grid = GridLayout::from_template(
+            "
+            nav  main
+            nav  main
+            .    status
+            ",
+            [GridSize::Fixed(5), GridSize::flex(1)],
+            [GridSize::flex(1), GridSize::flex(1), GridSize::Fixed(1)],
+        )
+        .unwrap();
+
+        let result = grid.solve(Size::new(20, 5)).unwrap();
+        assert_eq!(result["nav"], Rect::new(0, 0, 5, 4));
+        assert_eq!(result["main"], Rect::new(5, 0, 15, 4));
+        assert_eq!(result["status"], Rect::new(5, 4, 15, 1));
+    }
+
+    #[test]
+    fn test_grid_layout_from_template_rejects_malformed_templates() {
+        let flex = || GridSize::flex(1);
+
+        assert_eq!(
+            GridLayout::from_template(" / ", [], []).err(),
+            Some(GridError::EmptyTemplate)
+        );
+        assert_eq!(
+            GridLayout::from_template("a a / b", [flex(), flex()], [flex(), flex()]).err(),
+            Some(GridError::RaggedTemplate {
+                row: 1,
+                expected: 2,
+                found: 1,
+            })
+        );
+        assert_eq!(
+            GridLayout::from_template("a a / a b", [flex(), flex()], [flex(), flex()]).err(),
+            Some(GridError::NonRectangularArea { zone: "a".into() })
+        );
+        assert_eq!(
+            GridLayout::from_template("a b / c a", [flex(), flex()], [flex(), flex()]).err(),
+            Some(GridError::NonRectangularArea { zone: "a".into() })
+        );
+        assert_eq!(
+            GridLayout::from_template("a b", [flex()], [flex()]).err(),
+            Some(GridError::TrackCountMismatch {
+                axis: "columns",
+                template: 2,
+                tracks: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_grid_layout_from_template_accepts_namespaced_ids() {
+        let grid = GridLayout::from_template(
+            "main/toolbar main/toolbar / main/editor side",
+            [GridSize::flex(1), GridSize::Fixed(4)],
+            [GridSize::Fixed(1), GridSize::flex(1)],
+        )
+        .unwrap();
+
+        let result = grid.solve(Size::new(20, 5)).unwrap();
+        assert_eq!(result["main/toolbar"], Rect::new(0, 0, 20, 1));
+        assert_eq!(result["main/editor"], Rect::new(0, 1, 16, 4));
+    }
+
+    #[test]
+    fn test_grid_layout_describe_lists_spanned_tracks() {
+        let grid = GridLayout::from_template(
+            "header header / nav main",
+            vec![GridSize::Fixed(20), GridSize::flex(1)],
+            vec![GridSize::Fixed(1), GridSize::Auto],
+        )
+        .unwrap();
+
+        let rules = grid.describe();
+        assert_eq!(rules["header"], "Fixed(20)+Flex(1) × Fixed(1)");
+        assert_eq!(rules["nav"], "Fixed(20) × Auto");
+    }
+
+    #[test]
+    fn test_grid_layout_diagnose_reports_unused_tracks_and_overflow() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(30))
+            .add_col(GridSize::Fixed(30))
+            .add_row(GridSize::percent(70))
+            .add_row(GridSize::percent(50));
+        grid.place("main", GridArea::cell(0, 0)).unwrap();
+
+        let warnings = grid.diagnose(Size::new(40, 10));
+        assert_eq!(
+            warnings,
+            vec![
+                LayoutWarning::UnusedTrack {
+                    scope: "columns".into(),
+                    axis: "column",
+                    index: 1,
+                },
+                LayoutWarning::FixedOverflow {
+                    scope: "columns".into(),
+                    required: 60,
+                    available: 40,
+                },
+                LayoutWarning::UnusedTrack {
+                    scope: "rows".into(),
+                    axis: "row",
+                    index: 1,
+                },
+                LayoutWarning::PercentOverflow {
+                    scope: "rows".into(),
+                    total: 120,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid_layout_edits_move_remove_and_resize() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(10))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("nav", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+
+        let err = grid
+            .move_zone("main", GridArea::span_cols(0, 0..2))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            GridError::OverlappingAreas {
+                zone1: "nav".into(),
+                zone2: "main".into(),
+            }
+        );
+        assert_eq!(grid.area("main"), Some(&GridArea::cell(0, 1)));
+
+        grid.apply_edit(LayoutEdit::remove("nav")).unwrap();
+        grid.apply_edit(LayoutEdit::place("main", GridArea::span_cols(0, 0..2)))
+            .unwrap();
+        grid.apply_edit(LayoutEdit::SetCol {
+            index: 0,
+            size: GridSize::Fixed(4),
+        })
+        .unwrap();
+        let result = grid.solve(Size::new(30, 5)).unwrap();
+        assert!(!result.contains_key("nav"));
+        assert_eq!(result["main"], Rect::new(0, 0, 30, 5));
+
+        assert_eq!(
+            grid.apply_edit(LayoutEdit::remove("nav")),
+            Err(GridError::UnknownZone { zone: "nav".into() })
+        );
+        assert_eq!(
+            grid.apply_edit(LayoutEdit::SetRow {
+                index: 2,
+                size: GridSize::Auto,
+            }),
+            Err(GridError::TrackOutOfRange {
+                axis: "row",
+                index: 2,
+                count: 1,
+            })
+        );
+    }
+}
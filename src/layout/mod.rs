@@ -5,28 +5,124 @@
 
 use std::collections::HashMap;
 
-use crate::{Rect, Result, Size};
+use crate::{LayoutError, Rect, Result, Size};
 
+mod composite;
 mod core;
+mod diagnostics;
+mod edit;
 pub mod grid;
+mod responsive;
+mod spec;
 
+pub use composite::{CompositeLayout, ZONE_SEPARATOR};
 pub use core::{Constraint, Direction, LayoutNode, LayoutTree, NodeId};
+pub use diagnostics::LayoutWarning;
+pub use edit::LayoutEdit;
 pub use grid::{GridArea, GridError, GridLayout, GridSize, ZoneId};
+pub use responsive::{Breakpoint, ResponsiveLayout};
+pub use spec::LayoutSpec;
 
 /// Unified layout interface for calculating zone rectangles from terminal size.
 pub trait Layout {
     /// Calculate zone rectangles for the given terminal size.
     fn solve(&self, size: Size) -> Result<HashMap<String, Rect>>;
+
+    /// Calculate zone rectangles using the intrinsic content sizes zones
+    /// reported. Layouts without content-sized tracks ignore them.
+    fn solve_with_intrinsic(
+        &self,
+        size: Size,
+        _intrinsic: &HashMap<String, Size>,
+    ) -> Result<HashMap<String, Rect>> {
+        self.solve(size)
+    }
+
+    /// Human-readable sizing rule per zone (e.g. `Fixed(20)`) for the layout
+    /// that applies at `size`. Used by debugging tools such as the inspector.
+    fn describe(&self, _size: Size) -> HashMap<String, String> {
+        HashMap::new()
+    }
+
+    /// Like [`describe`](Layout::describe) for the layout solved with the
+    /// reported intrinsic sizes.
+    fn describe_with_intrinsic(
+        &self,
+        size: Size,
+        _intrinsic: &HashMap<String, Size>,
+    ) -> HashMap<String, String> {
+        self.describe(size)
+    }
+
+    /// Warnings about rules the solver had to bend at `size`, such as zones
+    /// collapsed to zero or constraints that overflow their container.
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        self.solve(size)
+            .map(|rects| diagnostics::collapsed_zones(&rects))
+            .unwrap_or_default()
+    }
+
+    /// Like [`diagnose`](Layout::diagnose) for the layout solved with the
+    /// reported intrinsic sizes.
+    fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        _intrinsic: &HashMap<String, Size>,
+    ) -> Vec<LayoutWarning> {
+        self.diagnose(size)
+    }
+
+    /// Apply an edit in place. Layouts that cannot be edited return
+    /// `LayoutError::EditUnsupported`.
+    fn apply_edit(&mut self, _edit: LayoutEdit) -> Result<()> {
+        Err(LayoutError::EditUnsupported)
+    }
 }
 
 impl Layout for GridLayout {
     fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
         GridLayout::solve(self, size).map_err(Into::into)
     }
+
+    fn solve_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Result<HashMap<String, Rect>> {
+        GridLayout::solve_with_intrinsic(self, size, intrinsic).map_err(Into::into)
+    }
+
+    fn describe(&self, _size: Size) -> HashMap<String, String> {
+        GridLayout::describe(self)
+    }
+
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        GridLayout::diagnose(self, size)
+    }
+
+    fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Vec<LayoutWarning> {
+        GridLayout::diagnose_with_intrinsic(self, size, intrinsic)
+    }
+
+    fn apply_edit(&mut self, edit: LayoutEdit) -> Result<()> {
+        GridLayout::apply_edit(self, edit).map_err(Into::into)
+    }
 }
 
 impl Layout for LayoutTree {
     fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
         LayoutTree::solve(self, size)
     }
+
+    fn describe(&self, _size: Size) -> HashMap<String, String> {
+        LayoutTree::describe(self)
+    }
+
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        LayoutTree::diagnose(self, size)
+    }
 }
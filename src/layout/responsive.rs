@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::geometry::{Rect, Size};
+
+use super::{Layout, LayoutWarning};
+
+/// Minimum terminal size at which a responsive variant applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Breakpoint {
+    pub min_width: u16,
+    pub min_height: u16,
+}
+
+impl Breakpoint {
+    pub const fn new(min_width: u16, min_height: u16) -> Self {
+        Self {
+            min_width,
+            min_height,
+        }
+    }
+
+    pub const fn min_width(min_width: u16) -> Self {
+        Self::new(min_width, 0)
+    }
+
+    pub const fn min_height(min_height: u16) -> Self {
+        Self::new(0, min_height)
+    }
+
+    pub fn matches(&self, size: Size) -> bool {
+        size.width >= self.min_width && size.height >= self.min_height
+    }
+}
+
+/// Layout that switches between variants as the terminal crosses size
+/// breakpoints.
+///
+/// The variant with the largest matching breakpoint (by width, then height)
+/// is solved; below every breakpoint the base layout is used. Because the
+/// runtime re-solves on every resize, the swap happens automatically. Zones
+/// that a variant does not place are hidden, not dropped, and plugins are told
+/// through `RoomPlugin::on_zone_visibility`.
+pub struct ResponsiveLayout {
+    base: Box<dyn Layout>,
+    variants: Vec<(Breakpoint, Box<dyn Layout>)>,
+}
+
+impl ResponsiveLayout {
+    pub fn new(base: impl Layout + 'static) -> Self {
+        Self {
+            base: Box::new(base),
+            variants: Vec::new(),
+        }
+    }
+
+    /// Use `layout` whenever the terminal is at least `breakpoint`. Adding a
+    /// breakpoint twice replaces the earlier layout.
+    pub fn with_breakpoint(
+        mut self,
+        breakpoint: Breakpoint,
+        layout: impl Layout + 'static,
+    ) -> Self {
+        self.variants
+            .retain(|(existing, _)| *existing != breakpoint);
+        self.variants.push((breakpoint, Box::new(layout)));
+        self.variants.sort_by(|(a, _), (b, _)| {
+            (b.min_width, b.min_height).cmp(&(a.min_width, a.min_height))
+        });
+        self
+    }
+
+    /// Breakpoint selected for `size`, or `None` when the base layout applies.
+    pub fn active_breakpoint(&self, size: Size) -> Option<Breakpoint> {
+        self.variants
+            .iter()
+            .find(|(breakpoint, _)| breakpoint.matches(size))
+            .map(|(breakpoint, _)| *breakpoint)
+    }
+
+    fn layout_for(&self, size: Size) -> &dyn Layout {
+        self.variants
+            .iter()
+            .find(|(breakpoint, _)| breakpoint.matches(size))
+            .map(|(_, layout)| layout.as_ref())
+            .unwrap_or(self.base.as_ref())
+    }
+}
+
+impl Layout for ResponsiveLayout {
+    fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
+        self.layout_for(size).solve(size)
+    }
+
+    fn solve_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Result<HashMap<String, Rect>> {
+        self.layout_for(size).solve_with_intrinsic(size, intrinsic)
+    }
+
+    fn describe(&self, size: Size) -> HashMap<String, String> {
+        self.layout_for(size).describe(size)
+    }
+
+    fn describe_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> HashMap<String, String> {
+        self.layout_for(size)
+            .describe_with_intrinsic(size, intrinsic)
+    }
+
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        self.layout_for(size).diagnose(size)
+    }
+
+    fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Vec<LayoutWarning> {
+        self.layout_for(size)
+            .diagnose_with_intrinsic(size, intrinsic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{GridArea, GridLayout, GridSize};
+
+    fn stacked() -> GridLayout {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::flex(1)).add_row(GridSize::flex(1));
+        grid.place("main", GridArea::cell(0, 0)).unwrap();
+        grid
+    }
+
+    fn with_sidebar(width: u16) -> GridLayout {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(width))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("sidebar", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+        grid
+    }
+
+    #[test]
+    fn picks_largest_matching_breakpoint() {
+        let layout = ResponsiveLayout::new(stacked())
+            .with_breakpoint(Breakpoint::min_width(120), with_sidebar(30))
+            .with_breakpoint(Breakpoint::min_width(60), with_sidebar(10));
+
+        let small = layout.solve(Size::new(40, 10)).unwrap();
+        assert_eq!(layout.active_breakpoint(Size::new(40, 10)), None);
+        assert!(!small.contains_key("sidebar"));
+        assert_eq!(small["main"], Rect::new(0, 0, 40, 10));
+
+        let medium = layout.solve(Size::new(80, 10)).unwrap();
+        assert_eq!(medium["sidebar"].width, 10);
+
+        let large = layout.solve(Size::new(150, 10)).unwrap();
+        assert_eq!(
+            layout.active_breakpoint(Size::new(150, 10)),
+            Some(Breakpoint::min_width(120))
+        );
+        assert_eq!(large["sidebar"].width, 30);
+    }
+}
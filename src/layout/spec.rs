@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{LayoutError, Result};
+use crate::geometry::{Rect, Size};
+
+use super::{GridLayout, Layout, LayoutEdit, LayoutTree, LayoutWarning};
+
+/// Layout description loaded from a file, tagged by `type`:
+///
+/// ```json
+/// { "type": "grid",
+///   "cols": [{"fixed": 20}, {"flex": 1}],
+///   "rows": [{"fixed": 1}, {"flex": 1}],
+///   "template": "header header / nav main" }
+/// ```
+///
+/// Trees use `{"type": "tree", "root": { ... }}` with nested `LayoutNode`s.
+/// The same structure can be written as TOML:
+///
+/// ```toml
+/// type = "grid"
+/// cols = [{ fixed = 20 }, { flex = 1 }]
+/// rows = [{ fixed = 1 }, { flex = 1 }]
+/// template = "header header / nav main"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum LayoutSpec {
+    Tree(LayoutTree),
+    Grid(GridLayout),
+}
+
+impl LayoutSpec {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| LayoutError::LayoutSpec(err.to_string()))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|err| LayoutError::LayoutSpec(err.to_string()))
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|err| LayoutError::LayoutSpec(err.to_string()))
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|err| LayoutError::LayoutSpec(err.to_string()))
+    }
+
+    /// Read a layout description from disk, as JSON (`.json`) or TOML
+    /// (`.toml`) depending on the extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse_file(path, &contents)
+    }
+
+    /// Parse the contents of the layout file at `path`, picking the format
+    /// from its extension.
+    pub(crate) fn parse_file(path: &Path, contents: &str) -> Result<Self> {
+        let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(contents),
+            Some("toml") => Self::from_toml(contents),
+            _ => {
+                return Err(LayoutError::LayoutSpec(format!(
+                    "unsupported layout file `{}` (expected .json or .toml)",
+                    path.display()
+                )));
+            }
+        };
+        parsed.map_err(|err| match err {
+            LayoutError::LayoutSpec(message) => {
+                LayoutError::LayoutSpec(format!("{}: {message}", path.display()))
+            }
+            other => other,
+        })
+    }
+}
+
+impl From<LayoutTree> for LayoutSpec {
+    fn from(tree: LayoutTree) -> Self {
+        Self::Tree(tree)
+    }
+}
+
+impl From<GridLayout> for LayoutSpec {
+    fn from(grid: GridLayout) -> Self {
+        Self::Grid(grid)
+    }
+}
+
+impl Layout for LayoutSpec {
+    fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::solve(tree, size),
+            LayoutSpec::Grid(grid) => Layout::solve(grid, size),
+        }
+    }
+
+    fn solve_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Result<HashMap<String, Rect>> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::solve_with_intrinsic(tree, size, intrinsic),
+            LayoutSpec::Grid(grid) => Layout::solve_with_intrinsic(grid, size, intrinsic),
+        }
+    }
+
+    fn describe(&self, size: Size) -> HashMap<String, String> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::describe(tree, size),
+            LayoutSpec::Grid(grid) => Layout::describe(grid, size),
+        }
+    }
+
+    fn describe_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> HashMap<String, String> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::describe_with_intrinsic(tree, size, intrinsic),
+            LayoutSpec::Grid(grid) => Layout::describe_with_intrinsic(grid, size, intrinsic),
+        }
+    }
+
+    fn diagnose(&self, size: Size) -> Vec<LayoutWarning> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::diagnose(tree, size),
+            LayoutSpec::Grid(grid) => Layout::diagnose(grid, size),
+        }
+    }
+
+    fn diagnose_with_intrinsic(
+        &self,
+        size: Size,
+        intrinsic: &HashMap<String, Size>,
+    ) -> Vec<LayoutWarning> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::diagnose_with_intrinsic(tree, size, intrinsic),
+            LayoutSpec::Grid(grid) => Layout::diagnose_with_intrinsic(grid, size, intrinsic),
+        }
+    }
+
+    fn apply_edit(&mut self, edit: LayoutEdit) -> Result<()> {
+        match self {
+            LayoutSpec::Tree(tree) => Layout::apply_edit(tree, edit),
+            LayoutSpec::Grid(grid) => Layout::apply_edit(grid, edit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{GridArea, GridError, GridSize};
+
+    #[test]
+    fn grid_round_trips_through_json() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(10))
+            .add_col(GridSize::min_max(5, 20))
+            .add_row(GridSize::Auto)
+            .with_gap(1);
+        grid.place("a", GridArea::cell(0, 0)).unwrap();
+        grid.place("b", GridArea::cell(0, 1)).unwrap();
+
+        let json = LayoutSpec::from(grid.clone()).to_json().unwrap();
+        let loaded = LayoutSpec::from_json(&json).unwrap();
+        let size = Size::new(40, 5);
+        assert_eq!(loaded.solve(size).unwrap(), grid.solve(size).unwrap());
+    }
+
+    #[test]
+    fn parses_templates_and_trees() {
+        let grid = LayoutSpec::from_json(
+            r#"{"type": "grid",
+                "cols": [{"fixed": 4}, {"flex": 1}],
+                "rows": ["auto", {"flex": 1}],
+                "template": "nav title / nav body"}"#,
+        )
+        .unwrap();
+        let rects = grid.solve(Size::new(20, 6)).unwrap();
+        assert_eq!(rects["nav"], Rect::new(0, 0, 4, 6));
+        assert_eq!(rects["body"], Rect::new(4, 1, 16, 5));
+
+        let tree = LayoutSpec::from_json(
+            r#"{"type": "tree", "root": {
+                "id": "root", "direction": "column",
+                "constraints": [{"fixed": 1}, {"flex": 1}],
+                "children": [{"id": "top"}, {"id": "rest"}]}}"#,
+        )
+        .unwrap();
+        let rects = tree.solve(Size::new(10, 4)).unwrap();
+        assert_eq!(rects["rest"], Rect::new(0, 1, 10, 3));
+    }
+
+    #[test]
+    fn loads_toml_files() {
+        let path = std::env::temp_dir().join(format!("room-spec-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+            type = "grid"
+            cols = [{ fixed = 4 }, { flex = 1 }]
+            rows = ["auto", { flex = 1 }]
+            template = "nav title / nav body"
+            "#,
+        )
+        .unwrap();
+        let loaded = LayoutSpec::load(&path);
+        std::fs::remove_file(&path).ok();
+
+        let rects = loaded.unwrap().solve(Size::new(20, 6)).unwrap();
+        assert_eq!(rects["nav"], Rect::new(0, 0, 4, 6));
+        assert_eq!(rects["body"], Rect::new(4, 1, 16, 5));
+
+        let tree = LayoutSpec::from_json(
+            r#"{"type": "tree", "root": {
+                "id": "root", "direction": "column",
+                "constraints": [{"fixed": 1}, {"flex": 1}],
+                "children": [{"id": "top"}, {"id": "rest"}]}}"#,
+        )
+        .unwrap();
+        let toml = tree.to_toml().unwrap();
+        let rects = LayoutSpec::from_toml(&toml)
+            .unwrap()
+            .solve(Size::new(10, 4));
+        assert_eq!(rects.unwrap()["rest"], Rect::new(0, 1, 10, 3));
+    }
+
+    #[test]
+    fn diagnoses_with_intrinsic_sizes() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Auto)
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("side", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+        let spec = LayoutSpec::from(grid.clone());
+
+        let size = Size::new(10, 3);
+        let intrinsic = HashMap::from([("side".to_string(), Size::new(10, 1))]);
+        let warnings = Layout::diagnose_with_intrinsic(&spec, size, &intrinsic);
+        assert_eq!(warnings, grid.diagnose_with_intrinsic(size, &intrinsic));
+        assert!(warnings.iter().any(|warning| matches!(
+            warning,
+            LayoutWarning::ZoneCollapsed { zone, .. } if zone == "main"
+        )));
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        let err = LayoutSpec::from_json(
+            r#"{"type": "grid", "cols": [{"flex": 1}], "rows": [{"flex": 1}],
+                "areas": {"a": {"rows": {"start": 0, "end": 2}, "cols": {"start": 0, "end": 1}}}}"#,
+        )
+        .unwrap_err();
+        let expected = GridError::OutOfBounds {
+            zone: "a".into(),
+            row_start: 0,
+            row_end: 2,
+            row_count: 1,
+            col_start: 0,
+            col_end: 1,
+            col_count: 1,
+        };
+        assert!(err.to_string().contains(&expected.to_string()), "{err}");
+    }
+}
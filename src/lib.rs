@@ -6,23 +6,30 @@
 
 pub mod error;
 pub mod geometry;
+pub mod headless;
 pub mod layout;
 pub mod logging;
 pub mod metrics;
 pub mod registry;
 pub mod render;
 pub mod runtime;
+pub mod style;
+pub mod testing;
 pub mod tokens;
 pub mod width;
 pub mod zone;
 
 pub use error::{LayoutError, Result};
 pub use geometry::{Rect, Size};
-pub use layout::{Constraint, Direction, GridArea, GridError, GridLayout, GridSize, Layout, LayoutNode, LayoutTree};
+pub use headless::VirtualTerminal;
+pub use layout::{
+    Breakpoint, CompositeLayout, Constraint, Direction, GridArea, GridError, GridLayout, GridSize,
+    Layout, LayoutEdit, LayoutNode, LayoutSpec, LayoutTree, LayoutWarning, ResponsiveLayout,
+};
 pub use logging::{LogEvent, LogFields, LogLevel, Logger, LoggingError, LoggingResult};
 pub use metrics::{MetricSnapshot, RuntimeMetrics};
-pub use registry::{ZoneContent, ZoneId, ZoneRegistry};
-pub use render::{AnsiRenderer, RendererSettings};
+pub use registry::{LogScroll, ZoneContent, ZoneId, ZoneLog, ZoneRegistry};
+pub use render::{AnsiRenderer, RendererSettings, SyncOutput};
 pub use runtime::BootstrapControls;
 pub use runtime::audit::{
     BootstrapAudit, NullRuntimeAudit, RuntimeAudit, RuntimeAuditEvent, RuntimeAuditEventBuilder,
@@ -30,24 +37,38 @@ pub use runtime::audit::{
 };
 pub use runtime::bundles::{
     DEFAULT_HINTS_ZONE, DEFAULT_INPUT_ZONE, DEFAULT_STATUS_ZONE, DefaultCliBundleConfig,
-    DiagnosticsConfig, DiagnosticsMetricsConfig, InputSharedState, SharedInputState,
-    default_cli_bundle, ensure_input_state, try_input_state,
+    DiagnosticsConfig, DiagnosticsMetricsConfig, InputSharedState, InputSubmitted,
+    SharedInputState, default_cli_bundle, ensure_input_state, try_input_state,
 };
 pub use runtime::diagnostics::{LifecycleLoggerPlugin, MetricsSnapshotPlugin};
 pub use runtime::driver::cli::{CliDriver, CliDriverError, DriverResult};
 pub use runtime::driver::socket::{SocketDriver, SocketDriverError};
+pub use runtime::external::{CustomEvent, EventSender, RuntimeClosed};
 pub use runtime::focus::{
     FocusController, FocusEntry, FocusRegistry, SharedFocus, ensure_focus_registry,
 };
+pub use runtime::hot_reload::LayoutReloadPlugin;
+pub use runtime::inspector::LayoutInspectorPlugin;
+pub use runtime::keymap::{
+    Action, DEFAULT_MODE, DEFAULT_SEQUENCE_TIMEOUT, KeyChord, KeyMatch, KeyScope, Keymap,
+    SharedKeymap, ensure_keymap,
+};
+pub use runtime::mouse::{DOUBLE_CLICK_INTERVAL, ZoneMouse, ZoneMouseKind};
 pub use runtime::screens::{
-    GlobalZoneStrategy, LegacyScreenStrategy, ScreenActivation, ScreenDefinition, ScreenFactory,
-    ScreenLifecycleEvent, ScreenManager, ScreenMetadata, ScreenNavigator, ScreenState,
+    GlobalZoneStrategy, LegacyScreenStrategy, NEXT_SCREEN_ACTION, PREVIOUS_SCREEN_ACTION,
+    ScreenActivation, ScreenDefinition, ScreenFactory, ScreenLifecycleEvent, ScreenManager,
+    ScreenMetadata, ScreenNavigator, ScreenState,
 };
 pub use runtime::shared_state::{SharedState, SharedStateError};
+pub use runtime::splitter::{SharedSplit, SplitAxis, SplitState, SplitterPlugin};
+pub use runtime::timers::{MIN_TIMER_PERIOD, TimerHandle};
 pub use runtime::{
     BoxConfig, CollapseMode, EventFlow, PluginBundle, RoomPlugin, RoomRuntime, RuntimeConfig,
     RuntimeContext, RuntimeEvent, SimulatedLoop,
 };
+pub use style::{
+    Color, HorizontalAlign, Overflow, Style, StyledSpan, StyledText, VerticalAlign, ZoneFormat,
+};
 pub use tokens::{ZoneTokenRouter, ZoneTokenUpdate};
 pub use width::display_width;
 pub mod cursor;
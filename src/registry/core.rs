@@ -1,9 +1,13 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use blake3::Hash;
 
 use crate::error::{LayoutError, Result};
 use crate::geometry::Rect;
+use crate::style::{StyledText, ZoneFormat};
+
+use super::log::{DEFAULT_LOG_CAPACITY, LogScroll, ZoneLog};
 
 pub type ZoneId = String;
 
@@ -14,16 +18,30 @@ pub type ZoneContent = String;
 pub struct ZoneState {
     pub rect: Rect,
     pub content: ZoneContent,
+    /// Styled payload; when present the renderer paints these spans and
+    /// `content` holds their plain text.
+    pub styled: Option<StyledText>,
+    /// Overflow and alignment applied when the zone is painted.
+    pub format: ZoneFormat,
+    /// Stacking order; layout zones sit at 0 and overlays above them.
+    pub z_index: i32,
+    /// Line history for log zones fed through `append_log`; when present it
+    /// replaces `content` and the renderer shows the viewport's lines.
+    pub log: Option<Arc<ZoneLog>>,
     hash: Option<Hash>,
     pub is_dirty: bool,
     pub is_pre_rendered: bool,
 }
 
 impl ZoneState {
-    fn new(rect: Rect) -> Self {
+    pub(crate) fn new(rect: Rect) -> Self {
         Self {
             rect,
             content: ZoneContent::new(),
+            styled: None,
+            format: ZoneFormat::default(),
+            z_index: 0,
+            log: None,
             hash: None,
             is_dirty: true,
             is_pre_rendered: false,
@@ -34,18 +52,92 @@ impl ZoneState {
         let new_hash = blake3::hash(content.as_bytes());
         if self.hash.map(|h| h != new_hash).unwrap_or(true) {
             self.content = content;
+            self.styled = None;
+            self.log = None;
             self.hash = Some(new_hash);
             self.is_dirty = true;
             self.is_pre_rendered = false;
         }
     }
+
+    fn update_styled(&mut self, styled: StyledText) {
+        let new_hash = hash_styled(&styled);
+        if self.hash.map(|h| h != new_hash).unwrap_or(true) {
+            self.content = styled.plain_text();
+            self.styled = Some(styled);
+            self.log = None;
+            self.hash = Some(new_hash);
+            self.is_dirty = true;
+            self.is_pre_rendered = false;
+        }
+    }
+
+    fn log_mut(&mut self) -> &mut ZoneLog {
+        if self.log.is_none() {
+            self.content.clear();
+            self.styled = None;
+            self.is_pre_rendered = false;
+            // Log zones skip content hashing; forget the last hash so the next
+            // `set_zone` is always applied.
+            self.hash = None;
+        }
+        Arc::make_mut(
+            self.log
+                .get_or_insert_with(|| Arc::new(ZoneLog::new(DEFAULT_LOG_CAPACITY))),
+        )
+    }
+}
+
+/// Hash styled spans so that identical text with different styles (or a plain
+/// payload with the same text) is still detected as a change.
+fn hash_styled(styled: &StyledText) -> Hash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"styled");
+    for span in styled.spans() {
+        hasher.update(&(span.text.len() as u64).to_le_bytes());
+        hasher.update(span.text.as_bytes());
+        hasher.update(span.style.to_sgr().as_bytes());
+        hasher.update(&[0]);
+    }
+    hasher.finalize()
+}
+
+/// Zone changes produced by [`ZoneRegistry::sync_layout`], each list sorted by
+/// zone id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutChanges {
+    /// Zones whose rect changed, as `(zone, old, new)`.
+    pub resized: Vec<(ZoneId, Rect, Rect)>,
+    /// Zones the layout no longer places. Their state is kept while hidden.
+    pub hidden: Vec<ZoneId>,
+    /// Hidden zones the layout places again.
+    pub shown: Vec<ZoneId>,
+}
+
+impl LayoutChanges {
+    pub fn is_empty(&self) -> bool {
+        self.resized.is_empty() && self.hidden.is_empty() && self.shown.is_empty()
+    }
 }
 
 /// Registry mapping layout zones to their last known states.
+///
+/// Besides the zones produced by the layout, the registry tracks overlay zones
+/// (popups, dropdowns, dialogs) placed at an explicit rect and z-index. Overlays
+/// survive layout syncs and are painted after the zones they cover. Whenever a
+/// zone is repainted, every higher zone overlapping it is repainted too, and
+/// closing an overlay re-dirties whatever it was hiding.
+///
+/// Zones dropped by a layout (for example below a responsive breakpoint) are
+/// hidden rather than forgotten: they keep accepting updates but are not
+/// painted until the layout places them again.
 #[derive(Debug, Default)]
 pub struct ZoneRegistry {
     entries: HashMap<ZoneId, ZoneState>,
     dirty: HashSet<ZoneId>,
+    overlays: HashSet<ZoneId>,
+    hidden: HashSet<ZoneId>,
+    exposed: Vec<Rect>,
 }
 
 impl ZoneRegistry {
@@ -53,19 +145,32 @@ impl ZoneRegistry {
         Self::default()
     }
 
-    pub fn sync_layout(&mut self, solved_rects: &HashMap<ZoneId, Rect>) {
+    /// Adopt freshly solved rects. Zones that changed size keep their content
+    /// and are re-wrapped to the new rect on the next paint; zones missing from
+    /// `solved_rects` are hidden with their state intact. The rects they left
+    /// are exposed so only the affected cells are repainted. The returned
+    /// changes let callers notify whoever owns those zones.
+    pub fn sync_layout(&mut self, solved_rects: &HashMap<ZoneId, Rect>) -> LayoutChanges {
         use std::collections::hash_map::Entry;
 
         let mut newly_dirty = Vec::new();
+        let mut changes = LayoutChanges::default();
 
         for (id, rect) in solved_rects {
+            if self.overlays.contains(id) {
+                continue;
+            }
             match self.entries.entry(id.clone()) {
                 Entry::Occupied(mut entry) => {
                     let state = entry.get_mut();
-                    if state.rect != *rect {
+                    if self.hidden.remove(id) {
+                        state.rect = *rect;
+                        state.is_dirty = true;
+                        newly_dirty.push(id.clone());
+                        changes.shown.push(id.clone());
+                    } else if state.rect != *rect {
+                        changes.resized.push((id.clone(), state.rect, *rect));
                         state.rect = *rect;
-                        state.content.clear();
-                        state.hash = None;
                         state.is_dirty = true;
                         newly_dirty.push(id.clone());
                     }
@@ -77,21 +182,51 @@ impl ZoneRegistry {
             }
         }
 
-        // Remove zones no longer present.
-        let to_remove: Vec<_> = self
+        // Hide zones no longer present; their rect is exposed for repainting.
+        let to_hide: Vec<_> = self
             .entries
-            .keys()
-            .filter(|id| !solved_rects.contains_key(*id))
-            .cloned()
+            .iter()
+            .filter(|(id, _)| {
+                !solved_rects.contains_key(*id)
+                    && !self.overlays.contains(*id)
+                    && !self.hidden.contains(*id)
+            })
+            .map(|(id, state)| (id.clone(), state.rect))
             .collect();
-        for id in to_remove {
-            self.entries.remove(&id);
+        for (id, rect) in to_hide {
             self.dirty.remove(&id);
+            self.hidden.insert(id.clone());
+            self.expose(rect);
+            changes.hidden.push(id);
         }
 
         for id in newly_dirty {
             self.dirty.insert(id);
         }
+        for (_, old, new) in &changes.resized {
+            if !new.covers(old) {
+                self.expose(*old);
+            }
+        }
+        changes.resized.sort_by(|a, b| a.0.cmp(&b.0));
+        changes.hidden.sort();
+        changes.shown.sort();
+        changes
+    }
+
+    /// Flag every visible zone for repaint, e.g. after the screen was cleared.
+    pub fn mark_all_dirty(&mut self) {
+        for (id, state) in &mut self.entries {
+            if !self.hidden.contains(id) {
+                state.is_dirty = true;
+                self.dirty.insert(id.clone());
+            }
+        }
+    }
+
+    /// Whether the zone is known but currently not placed by the layout.
+    pub fn is_hidden(&self, zone_id: &ZoneId) -> bool {
+        self.hidden.contains(zone_id)
     }
 
     pub fn apply_content(&mut self, zone_id: &ZoneId, content: ZoneContent) -> Result<()> {
@@ -118,25 +253,194 @@ impl ZoneRegistry {
         Ok(())
     }
 
+    pub fn apply_styled(&mut self, zone_id: &ZoneId, styled: StyledText) -> Result<()> {
+        let entry = self
+            .entries
+            .get_mut(zone_id)
+            .ok_or_else(|| LayoutError::ZoneNotFound(zone_id.clone()))?;
+        entry.update_styled(styled);
+        if entry.is_dirty {
+            self.dirty.insert(zone_id.clone());
+        }
+        Ok(())
+    }
+
+    /// Change how a zone's text is wrapped and aligned. The zone is repainted
+    /// only when the format actually changes.
+    pub fn apply_format(&mut self, zone_id: &ZoneId, format: ZoneFormat) -> Result<()> {
+        let entry = self
+            .entries
+            .get_mut(zone_id)
+            .ok_or_else(|| LayoutError::ZoneNotFound(zone_id.clone()))?;
+        if entry.format != format {
+            entry.format = format;
+            entry.is_dirty = true;
+            self.dirty.insert(zone_id.clone());
+        }
+        Ok(())
+    }
+
+    /// Append a line to a log zone, turning the zone into one (with the default
+    /// capacity) if needed. Only the new line is stored; nothing is rehashed.
+    pub fn append_log(&mut self, zone_id: &ZoneId, line: impl Into<String>) -> Result<()> {
+        let entry = self
+            .entries
+            .get_mut(zone_id)
+            .ok_or_else(|| LayoutError::ZoneNotFound(zone_id.clone()))?;
+        let page = entry.rect.height as usize;
+        entry.log_mut().push(line, page);
+        entry.is_dirty = true;
+        self.dirty.insert(zone_id.clone());
+        Ok(())
+    }
+
+    pub fn set_log_capacity(&mut self, zone_id: &ZoneId, capacity: usize) -> Result<()> {
+        let entry = self
+            .entries
+            .get_mut(zone_id)
+            .ok_or_else(|| LayoutError::ZoneNotFound(zone_id.clone()))?;
+        let page = entry.rect.height as usize;
+        entry.log_mut().set_capacity(capacity, page);
+        entry.is_dirty = true;
+        self.dirty.insert(zone_id.clone());
+        Ok(())
+    }
+
+    /// Move a log zone's viewport. Pages are the zone height. Returns whether
+    /// the view moved; zones without a log are left untouched.
+    pub fn scroll_log(&mut self, zone_id: &ZoneId, scroll: LogScroll) -> Result<bool> {
+        let entry = self
+            .entries
+            .get_mut(zone_id)
+            .ok_or_else(|| LayoutError::ZoneNotFound(zone_id.clone()))?;
+        let page = entry.rect.height as usize;
+        let moved = match entry.log.as_mut() {
+            Some(log) => Arc::make_mut(log).scroll(scroll, page),
+            None => false,
+        };
+        if moved {
+            entry.is_dirty = true;
+            self.dirty.insert(zone_id.clone());
+        }
+        Ok(moved)
+    }
+
+    pub fn is_log(&self, zone_id: &ZoneId) -> bool {
+        self.entries
+            .get(zone_id)
+            .is_some_and(|state| state.log.is_some())
+    }
+
+    /// Place (or move) an overlay zone above the layout. Moving an overlay
+    /// re-dirties whatever its previous rect was covering.
+    pub fn open_overlay(&mut self, zone_id: &ZoneId, rect: Rect, z_index: i32) -> Result<()> {
+        if self.entries.contains_key(zone_id) && !self.overlays.contains(zone_id) {
+            return Err(LayoutError::Backend(format!(
+                "zone `{zone_id}` is a layout zone and cannot be opened as an overlay"
+            )));
+        }
+
+        match self.entries.get_mut(zone_id) {
+            Some(state) => {
+                if state.rect == rect && state.z_index == z_index {
+                    return Ok(());
+                }
+                let previous = state.rect;
+                state.rect = rect;
+                state.z_index = z_index;
+                state.is_dirty = true;
+                self.expose(previous);
+            }
+            None => {
+                let mut state = ZoneState::new(rect);
+                state.z_index = z_index;
+                self.entries.insert(zone_id.clone(), state);
+                self.overlays.insert(zone_id.clone());
+            }
+        }
+        self.dirty.insert(zone_id.clone());
+        Ok(())
+    }
+
+    /// Remove an overlay and repaint the zones underneath it.
+    pub fn close_overlay(&mut self, zone_id: &ZoneId) -> Result<()> {
+        if !self.overlays.remove(zone_id) {
+            return Err(LayoutError::ZoneNotFound(zone_id.clone()));
+        }
+        self.dirty.remove(zone_id);
+        if let Some(state) = self.entries.remove(zone_id) {
+            self.expose(state.rect);
+        }
+        Ok(())
+    }
+
+    pub fn is_overlay(&self, zone_id: &ZoneId) -> bool {
+        self.overlays.contains(zone_id)
+    }
+
+    /// Rects of the open overlays, keyed by zone id.
+    pub fn overlay_rects(&self) -> HashMap<ZoneId, Rect> {
+        self.overlays
+            .iter()
+            .filter_map(|id| self.entries.get(id).map(|state| (id.clone(), state.rect)))
+            .collect()
+    }
+
+    /// Regions uncovered since the last call (closed or moved overlays, moved
+    /// or hidden zones). The
+    /// renderer blanks them before painting so cells not owned by any zone do
+    /// not keep stale overlay content.
+    pub fn take_exposed(&mut self) -> Vec<Rect> {
+        std::mem::take(&mut self.exposed)
+    }
+
+    fn expose(&mut self, rect: Rect) {
+        for (id, state) in &mut self.entries {
+            if state.rect.intersects(&rect) && !self.hidden.contains(id) {
+                state.is_dirty = true;
+                self.dirty.insert(id.clone());
+            }
+        }
+        self.exposed.push(rect);
+    }
+
+    /// Drain dirty zones in paint order: ascending z-index, then top-to-bottom,
+    /// left-to-right. A dirty zone also pulls in every higher zone overlapping
+    /// it so repainting a base zone never erases the overlay on top.
     pub fn take_dirty(&mut self) -> Vec<(ZoneId, ZoneState)> {
-        let mut ids: Vec<_> = self.dirty.drain().collect();
-        ids.sort_by(|a, b| {
-            let rect_a = self
-                .entries
-                .get(a)
-                .map(|state| state.rect)
-                .unwrap_or(Rect::new(0, 0, 0, 0));
-            let rect_b = self
-                .entries
-                .get(b)
-                .map(|state| state.rect)
-                .unwrap_or(Rect::new(0, 0, 0, 0));
-            rect_a
-                .y
-                .cmp(&rect_b.y)
-                .then_with(|| rect_a.x.cmp(&rect_b.x))
-                .then_with(|| a.cmp(b))
-        });
+        // Hidden zones stay dirty until they are shown again.
+        let hidden = &self.hidden;
+        let mut ids: Vec<_> = self
+            .dirty
+            .iter()
+            .filter(|id| !hidden.contains(*id))
+            .cloned()
+            .collect();
+        self.dirty.retain(|id| hidden.contains(id));
+        let mut idx = 0;
+        while idx < ids.len() {
+            if let Some(state) = self.entries.get(&ids[idx]) {
+                let (rect, z_index) = (state.rect, state.z_index);
+                for (other_id, other) in &self.entries {
+                    if other.z_index > z_index
+                        && other.rect.intersects(&rect)
+                        && !self.hidden.contains(other_id)
+                        && !ids.contains(other_id)
+                    {
+                        ids.push(other_id.clone());
+                    }
+                }
+            }
+            idx += 1;
+        }
+
+        let sort_key = |id: &ZoneId| {
+            self.entries
+                .get(id)
+                .map(|state| (state.z_index, state.rect.y, state.rect.x))
+                .unwrap_or((0, 0, 0))
+        };
+        ids.sort_by(|a, b| sort_key(a).cmp(&sort_key(b)).then_with(|| a.cmp(b)));
         ids.into_iter()
             .filter_map(|id| {
                 self.entries.get_mut(&id).map(|state| {
@@ -148,11 +452,30 @@ impl ZoneRegistry {
     }
 
     pub fn rect_of(&self, zone_id: &ZoneId) -> Option<Rect> {
+        if self.hidden.contains(zone_id) {
+            return None;
+        }
         self.entries.get(zone_id).map(|state| state.rect)
     }
 
+    /// Topmost visible zone covering the cell at `x`, `y`: the highest
+    /// z-index wins, then the smallest zone so nested zones beat their parents.
+    pub fn zone_at(&self, x: u16, y: u16) -> Option<(ZoneId, Rect)> {
+        self.entries
+            .iter()
+            .filter(|(id, state)| state.rect.contains(x, y) && !self.hidden.contains(*id))
+            .max_by(|(a_id, a), (b_id, b)| {
+                let area = |rect: &Rect| rect.width as u32 * rect.height as u32;
+                a.z_index
+                    .cmp(&b.z_index)
+                    .then_with(|| area(&b.rect).cmp(&area(&a.rect)))
+                    .then_with(|| b_id.cmp(a_id))
+            })
+            .map(|(id, state)| (id.clone(), state.rect))
+    }
+
     pub fn has_dirty(&self) -> bool {
-        !self.dirty.is_empty()
+        self.dirty.iter().any(|id| !self.hidden.contains(id)) || !self.exposed.is_empty()
     }
 }
 
@@ -177,6 +500,63 @@ mod tests {
         assert_eq!(dirty[0].0, "zone");
     }
 
+    #[test]
+    fn sync_layout_keeps_content_of_resized_zones() {
+        let mut registry = ZoneRegistry::new();
+        let zone = "zone".to_string();
+        let mut solved = HashMap::new();
+        solved.insert(zone.clone(), rect());
+        solved.insert("other".to_string(), Rect::new(0, 5, 10, 1));
+        registry.sync_layout(&solved);
+        registry.apply_content(&zone, "hello".to_string()).unwrap();
+        registry.take_dirty();
+
+        let wider = Rect::new(0, 0, 20, 5);
+        solved.insert(zone.clone(), wider);
+        let changes = registry.sync_layout(&solved);
+        assert_eq!(changes.resized, vec![(zone.clone(), rect(), wider)]);
+
+        let dirty = registry.take_dirty();
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].1.content, "hello");
+        assert_eq!(dirty[0].1.rect, wider);
+
+        // Unchanged content is still deduplicated after the resize.
+        registry.apply_content(&zone, "hello".to_string()).unwrap();
+        assert!(!registry.has_dirty());
+    }
+
+    #[test]
+    fn sync_layout_hides_and_restores_missing_zones() {
+        let mut registry = ZoneRegistry::new();
+        let zone = "zone".to_string();
+        let mut solved = HashMap::new();
+        solved.insert(zone.clone(), rect());
+        registry.sync_layout(&solved);
+        registry.apply_content(&zone, "kept".to_string()).unwrap();
+        registry.take_dirty();
+
+        let changes = registry.sync_layout(&HashMap::new());
+        assert_eq!(changes.hidden, vec![zone.clone()]);
+        assert!(registry.is_hidden(&zone));
+        assert_eq!(registry.rect_of(&zone), None);
+
+        assert_eq!(registry.take_exposed(), vec![rect()]);
+
+        // Hidden zones still accept updates but are never painted.
+        registry
+            .apply_content(&zone, "updated".to_string())
+            .unwrap();
+        assert!(!registry.has_dirty());
+        assert!(registry.take_dirty().is_empty());
+
+        let changes = registry.sync_layout(&solved);
+        assert_eq!(changes.shown, vec![zone.clone()]);
+        let dirty = registry.take_dirty();
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].1.content, "updated");
+    }
+
     #[test]
     fn apply_content_detects_changes() {
         let mut registry = ZoneRegistry::new();
@@ -197,4 +577,115 @@ mod tests {
         let dirty_again = registry.take_dirty();
         assert!(dirty_again.is_empty());
     }
+
+    #[test]
+    fn apply_styled_detects_style_only_changes() {
+        use crate::style::{Color, Style};
+
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("zone".to_string(), rect());
+        registry.sync_layout(&solved);
+        registry.take_dirty();
+
+        let zone = "zone".to_string();
+        registry.apply_content(&zone, "hello".to_string()).unwrap();
+        registry.take_dirty();
+
+        registry
+            .apply_styled(&zone, StyledText::from("hello"))
+            .unwrap();
+        assert_eq!(registry.take_dirty().len(), 1);
+
+        let red = StyledText::new().span("hello", Style::new().fg(Color::Red));
+        registry.apply_styled(&zone, red.clone()).unwrap();
+        let dirty = registry.take_dirty();
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].1.content, "hello");
+        assert_eq!(dirty[0].1.styled.as_ref(), Some(&red));
+
+        registry.apply_styled(&zone, red).unwrap();
+        assert!(registry.take_dirty().is_empty());
+    }
+
+    #[test]
+    fn overlays_paint_above_and_expose_on_close() {
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("left".to_string(), Rect::new(0, 0, 5, 5));
+        solved.insert("right".to_string(), Rect::new(5, 0, 5, 5));
+        registry.sync_layout(&solved);
+        registry.take_dirty();
+
+        let popup = "popup".to_string();
+        registry
+            .open_overlay(&popup, Rect::new(3, 1, 4, 2), 10)
+            .unwrap();
+        registry.sync_layout(&solved);
+        assert!(registry.is_overlay(&popup));
+
+        // Repainting a covered base zone pulls the overlay in after it.
+        registry
+            .apply_content(&"left".to_string(), "hi".to_string())
+            .unwrap();
+        let order: Vec<_> = registry
+            .take_dirty()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(order, vec!["left".to_string(), popup.clone()]);
+
+        registry.close_overlay(&popup).unwrap();
+        let order: Vec<_> = registry
+            .take_dirty()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(order, vec!["left".to_string(), "right".to_string()]);
+        assert_eq!(registry.take_exposed(), vec![Rect::new(3, 1, 4, 2)]);
+        assert!(registry.close_overlay(&popup).is_err());
+    }
+
+    #[test]
+    fn log_zones_append_without_replacing_content() {
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("log".to_string(), Rect::new(0, 0, 10, 2));
+        registry.sync_layout(&solved);
+        registry.take_dirty();
+
+        let zone = "log".to_string();
+        registry.set_log_capacity(&zone, 3).unwrap();
+        for line in ["a", "b", "c", "d"] {
+            registry.append_log(&zone, line).unwrap();
+        }
+        let dirty = registry.take_dirty();
+        let log = dirty[0].1.log.as_ref().expect("log zone");
+        assert_eq!(log.lines().collect::<Vec<_>>(), vec!["b", "c", "d"]);
+        drop(dirty);
+
+        assert!(registry.scroll_log(&zone, LogScroll::PageUp).unwrap());
+        assert_eq!(registry.take_dirty().len(), 1);
+
+        registry.apply_content(&zone, "plain".to_string()).unwrap();
+        assert!(!registry.is_log(&zone));
+    }
+
+    #[test]
+    fn zone_at_picks_the_topmost_zone() {
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("left".to_string(), Rect::new(0, 0, 5, 3));
+        solved.insert("right".to_string(), Rect::new(5, 0, 5, 3));
+        registry.sync_layout(&solved);
+        registry
+            .open_overlay(&"popup".to_string(), Rect::new(3, 1, 4, 1), 10)
+            .unwrap();
+
+        let zone = |x, y| registry.zone_at(x, y).map(|(id, _)| id);
+        assert_eq!(zone(0, 0).as_deref(), Some("left"));
+        assert_eq!(zone(9, 2).as_deref(), Some("right"));
+        assert_eq!(zone(4, 1).as_deref(), Some("popup"));
+        assert_eq!(zone(10, 0), None);
+    }
 }
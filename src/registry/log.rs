@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+
+/// Default number of lines retained by a log zone.
+pub const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+/// Scroll request for a log zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogScroll {
+    /// Move the viewport towards older lines.
+    Up(usize),
+    /// Move the viewport towards newer lines.
+    Down(usize),
+    /// Scroll up by one viewport height.
+    PageUp,
+    /// Scroll down by one viewport height.
+    PageDown,
+    /// Jump to the oldest retained line.
+    Top,
+    /// Jump to the newest line and resume following the tail.
+    Bottom,
+}
+
+/// Append-only line history backing a log zone.
+///
+/// Lines live in a bounded ring buffer; once `capacity` is reached the oldest
+/// line is dropped. The viewport is described by `offset`, the number of lines
+/// hidden below it: `0` follows the tail, anything else pins the view so new
+/// lines do not move it. Methods taking a `page` expect the viewport height,
+/// which keeps the oldest page from scrolling past the top.
+#[derive(Debug, Clone)]
+pub struct ZoneLog {
+    lines: VecDeque<String>,
+    capacity: usize,
+    offset: usize,
+}
+
+impl ZoneLog {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            lines: VecDeque::with_capacity(capacity.min(DEFAULT_LOG_CAPACITY)),
+            capacity,
+            offset: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Lines hidden below the viewport; `0` means the view follows the tail.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Shrink or grow the history, dropping the oldest lines if needed.
+    pub fn set_capacity(&mut self, capacity: usize, page: usize) {
+        self.capacity = capacity.max(1);
+        while self.lines.len() > self.capacity {
+            self.lines.pop_front();
+        }
+        self.offset = self.offset.min(self.max_offset(page));
+    }
+
+    pub fn push(&mut self, line: impl Into<String>, page: usize) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        if !self.is_following() {
+            // Keep a scrolled-back view on the same lines until the oldest
+            // of them is dropped.
+            self.offset += 1;
+        }
+        self.lines.push_back(line.into());
+        self.offset = self.offset.min(self.max_offset(page));
+    }
+
+    /// Apply a scroll request; `page` is the viewport height. Returns whether
+    /// the viewport moved.
+    pub fn scroll(&mut self, scroll: LogScroll, page: usize) -> bool {
+        let page = page.max(1);
+        let offset = self.offset.min(self.max_offset(page));
+        let target = match scroll {
+            LogScroll::Up(lines) => offset.saturating_add(lines),
+            LogScroll::Down(lines) => offset.saturating_sub(lines),
+            LogScroll::PageUp => offset.saturating_add(page),
+            LogScroll::PageDown => offset.saturating_sub(page),
+            LogScroll::Top => usize::MAX,
+            LogScroll::Bottom => 0,
+        };
+        let target = target.min(self.max_offset(page));
+        let moved = target != offset;
+        self.offset = target;
+        moved
+    }
+
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Lines up to the bottom of a viewport `page` lines high, newest last.
+    /// The offset is clamped so a zone that grew still shows a full page.
+    pub fn visible_lines(&self, page: usize) -> impl DoubleEndedIterator<Item = &str> {
+        let offset = self.offset.min(self.max_offset(page));
+        self.lines
+            .iter()
+            .take(self.lines.len() - offset)
+            .map(String::as_str)
+    }
+
+    fn max_offset(&self, page: usize) -> usize {
+        self.lines.len().saturating_sub(page.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_buffer_drops_oldest_lines() {
+        let mut log = ZoneLog::new(2);
+        log.push("a", 1);
+        log.push("b", 1);
+        log.push("c", 1);
+        assert_eq!(log.lines().collect::<Vec<_>>(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn scrolled_view_stays_pinned_while_lines_arrive() {
+        let mut log = ZoneLog::new(10);
+        for line in ["1", "2", "3", "4"] {
+            log.push(line, 2);
+        }
+        assert!(log.scroll(LogScroll::PageUp, 2));
+        assert_eq!(log.visible_lines(2).last(), Some("2"));
+
+        log.push("5", 2);
+        assert_eq!(log.visible_lines(2).last(), Some("2"));
+
+        assert!(log.scroll(LogScroll::Bottom, 2));
+        assert!(log.is_following());
+        assert_eq!(log.visible_lines(2).last(), Some("5"));
+        assert!(!log.scroll(LogScroll::Down(3), 2));
+
+        let mut full = ZoneLog::new(6);
+        for line in 1..=6 {
+            full.push(line.to_string(), 2);
+        }
+        assert!(full.scroll(LogScroll::PageUp, 2));
+        for line in 7..=8 {
+            full.push(line.to_string(), 2);
+            assert_eq!(full.visible_lines(2).last(), Some("4"));
+        }
+        full.push("9", 2);
+        assert_eq!(full.visible_lines(2).collect::<Vec<_>>(), vec!["4", "5"]);
+    }
+
+    #[test]
+    fn top_shows_a_full_page_of_the_oldest_lines() {
+        let mut log = ZoneLog::new(10);
+        for line in 1..=6 {
+            log.push(format!("line {line}"), 3);
+        }
+        assert!(log.scroll(LogScroll::Top, 3));
+        assert_eq!(
+            log.visible_lines(3).collect::<Vec<_>>(),
+            vec!["line 1", "line 2", "line 3"]
+        );
+        assert!(!log.scroll(LogScroll::PageUp, 3));
+        assert!(log.scroll(LogScroll::PageDown, 3));
+        assert_eq!(log.visible_lines(3).last(), Some("line 6"));
+    }
+}
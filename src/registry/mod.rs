@@ -1,5 +1,7 @@
 //! Zone registry module orchestrator following Module Spec guidelines.
 
 mod core;
+mod log;
 
-pub use core::{ZoneContent, ZoneId, ZoneRegistry, ZoneState};
+pub use core::{LayoutChanges, ZoneContent, ZoneId, ZoneRegistry, ZoneState};
+pub use log::{DEFAULT_LOG_CAPACITY, LogScroll, ZoneLog};
@@ -0,0 +1,170 @@
+use crate::style::Style;
+
+/// A single terminal cell captured by the renderer.
+///
+/// `symbol` holds the grapheme drawn in the cell (a base character plus any
+/// zero-width combining marks). Wide glyphs occupy two cells: the leading cell
+/// carries the symbol and the trailing cell is a continuation with an empty
+/// symbol. `style` is the style that must be active when the symbol is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: String,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(symbol: impl Into<String>, style: Style) -> Self {
+        Self {
+            symbol: symbol.into(),
+            style,
+        }
+    }
+
+    /// Unstyled space, matching what the terminal shows after a clear.
+    pub fn blank() -> Self {
+        Self::new(" ", Style::default())
+    }
+
+    /// Trailing half of a wide glyph.
+    pub fn continuation(style: Style) -> Self {
+        Self::new("", style)
+    }
+
+    pub fn is_continuation(&self) -> bool {
+        self.symbol.is_empty()
+    }
+}
+
+/// Fixed-size grid of optional cells.
+///
+/// The renderer keeps two of these: the back buffer collects cells painted for
+/// the current frame (`None` means "not touched this frame"), while the front
+/// buffer mirrors what the terminal is believed to show (`None` means
+/// "unknown", forcing the next paint of that cell to be emitted).
+#[derive(Debug, Clone, Default)]
+pub struct FrameBuffer {
+    width: u16,
+    height: u16,
+    cells: Vec<Option<Cell>>,
+}
+
+impl FrameBuffer {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![None; width as usize * height as usize],
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Grow the buffer so it covers at least `width` x `height`, keeping the
+    /// cells that are already known.
+    pub fn ensure_size(&mut self, width: u16, height: u16) {
+        if width <= self.width && height <= self.height {
+            return;
+        }
+
+        let new_width = width.max(self.width);
+        let new_height = height.max(self.height);
+        let mut cells = vec![None; new_width as usize * new_height as usize];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let from = self.index(x, y);
+                cells[y as usize * new_width as usize + x as usize] = self.cells[from].take();
+            }
+        }
+        self.width = new_width;
+        self.height = new_height;
+        self.cells = cells;
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells[self.index(x, y)].as_ref()
+    }
+
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = self.index(x, y);
+        self.cells[idx] = Some(cell);
+    }
+
+    pub fn take(&mut self, x: u16, y: u16) -> Option<Cell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let idx = self.index(x, y);
+        self.cells[idx].take()
+    }
+
+    /// Mark every cell as unknown/untouched.
+    pub fn reset(&mut self) {
+        self.cells.iter_mut().for_each(|cell| *cell = None);
+    }
+
+    /// Fill every cell with a blank, mirroring a full-screen clear.
+    pub fn fill_blank(&mut self) {
+        self.cells
+            .iter_mut()
+            .for_each(|cell| *cell = Some(Cell::blank()));
+    }
+
+    /// Move rows `top..bottom` up by `lines`, filling the rows scrolled in at
+    /// the bottom with blanks, mirroring a terminal scroll region scrolling up.
+    pub fn scroll_up(&mut self, top: u16, bottom: u16, lines: u16) {
+        let bottom = bottom.min(self.height);
+        let width = self.width as usize;
+        for y in top..bottom {
+            let from = y.saturating_add(lines);
+            for x in 0..self.width {
+                let idx = self.index(x, y);
+                self.cells[idx] = if from < bottom {
+                    self.cells[from as usize * width + x as usize].take()
+                } else {
+                    Some(Cell::blank())
+                };
+            }
+        }
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ensure_size_preserves_known_cells() {
+        let mut buffer = FrameBuffer::new(2, 2);
+        buffer.set(1, 1, Cell::new("x", Style::default()));
+        buffer.ensure_size(4, 3);
+
+        assert_eq!(buffer.width(), 4);
+        assert_eq!(buffer.height(), 3);
+        assert_eq!(buffer.get(1, 1), Some(&Cell::new("x", Style::default())));
+        assert_eq!(buffer.get(3, 2), None);
+    }
+
+    #[test]
+    fn out_of_bounds_access_is_ignored() {
+        let mut buffer = FrameBuffer::new(1, 1);
+        buffer.set(5, 5, Cell::blank());
+        assert_eq!(buffer.get(5, 5), None);
+        assert_eq!(buffer.take(5, 5), None);
+    }
+}
@@ -1,15 +1,45 @@
 use std::io::Write;
 
+use unicode_width::UnicodeWidthChar;
+
+use super::buffer::{Cell, FrameBuffer};
+use super::wrap::{layout_lines, line_width};
 use crate::display_width;
 use crate::error::Result;
-use crate::geometry::Rect;
-use crate::registry::{ZoneId, ZoneState};
+use crate::geometry::{Rect, Size};
+use crate::registry::{ZoneId, ZoneLog, ZoneState};
+use crate::style::{HorizontalAlign, Overflow, Style, StyledSpan, StyledText, VerticalAlign};
+
+const SYNC_BEGIN: &[u8] = b"\x1b[?2026h";
+const SYNC_END: &[u8] = b"\x1b[?2026l";
+
+/// Whether frames are wrapped in DEC private mode 2026 (synchronized update).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyncOutput {
+    /// Enable when the environment identifies a terminal known to support it.
+    /// `ROOM_SYNC_OUTPUT=1|0` forces the decision either way.
+    #[default]
+    Auto,
+    Enabled,
+    Disabled,
+}
+
+impl SyncOutput {
+    fn resolve(self) -> bool {
+        match self {
+            SyncOutput::Enabled => true,
+            SyncOutput::Disabled => false,
+            SyncOutput::Auto => detect_synchronized_output(),
+        }
+    }
+}
 
 /// Renderer runtime parameters.
 #[derive(Debug, Clone)]
 pub struct RendererSettings {
     pub restore_cursor: Option<(u16, u16)>,
     pub cursor_visible: Option<bool>,
+    pub synchronized_output: SyncOutput,
 }
 
 impl Default for RendererSettings {
@@ -17,18 +47,42 @@ impl Default for RendererSettings {
         Self {
             restore_cursor: None,
             cursor_visible: None,
+            synchronized_output: SyncOutput::Auto,
         }
     }
 }
 
 /// ANSI escape code renderer writing directly to a terminal handle.
+///
+/// Dirty zones are painted into a back buffer and compared cell by cell with
+/// the front buffer (the last frame written to the terminal). Only cells that
+/// changed are emitted, so a one-character update costs a cursor move and a
+/// glyph instead of a full zone repaint.
+///
+/// Log zones that span the full width and follow their tail are scrolled with
+/// a scroll region (`DECSTBM` plus `SU`) when the new frame is the old one
+/// shifted up, so appending a line only emits that line.
+///
+/// Each frame is assembled in memory and handed to the writer in a single
+/// write, wrapped in a synchronized-update block when the terminal supports
+/// it, so the terminal never shows a half-updated frame.
 pub struct AnsiRenderer {
     settings: RendererSettings,
+    front: FrameBuffer,
+    back: FrameBuffer,
+    clear_pending: bool,
+    sync_resolved: Option<(SyncOutput, bool)>,
 }
 
 impl AnsiRenderer {
     pub fn new(settings: RendererSettings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            front: FrameBuffer::default(),
+            back: FrameBuffer::default(),
+            clear_pending: false,
+            sync_resolved: None,
+        }
     }
 
     pub fn with_default() -> Self {
@@ -39,30 +93,225 @@ impl AnsiRenderer {
         &mut self.settings
     }
 
+    /// Resize the frame buffers to the terminal size. The terminal contents are
+    /// unknown afterwards, so the next frame re-emits every painted cell.
+    pub fn resize(&mut self, size: Size) {
+        self.front = FrameBuffer::new(size.width, size.height);
+        self.back = FrameBuffer::new(size.width, size.height);
+    }
+
+    /// Forget what the terminal shows so the next frame re-emits every painted cell.
+    pub fn invalidate(&mut self) {
+        self.front.reset();
+    }
+
+    /// Record that the terminal was just cleared (`ESC[2J`), so blank cells do
+    /// not need to be re-emitted.
+    pub fn mark_cleared(&mut self) {
+        self.front.fill_blank();
+    }
+
+    /// Clear the screen (`ESC[2J`) at the start of the next frame, inside the
+    /// same write as the repaint that follows it.
+    pub fn request_clear(&mut self) {
+        self.clear_pending = true;
+    }
+
+    /// Paint blanks over regions no longer covered by a zone (for example a
+    /// closed overlay or a pane that shrank). Zones rendered in the same frame
+    /// paint over them, so only cells owned by nothing end up cleared. Cells
+    /// outside the frame buffers are skipped.
+    pub fn blank_regions(&mut self, regions: &[Rect]) {
+        for rect in regions {
+            for y in rect.y..rect.bottom().min(self.back.height()) {
+                for x in rect.x..rect.right().min(self.back.width()) {
+                    self.back.set(x, y, Cell::blank());
+                }
+            }
+        }
+    }
+
     pub fn render(&mut self, writer: &mut impl Write, dirty: &[(ZoneId, ZoneState)]) -> Result<()> {
+        let (width, height) = dirty.iter().fold((0u16, 0u16), |(w, h), (_id, state)| {
+            (w.max(state.rect.right()), h.max(state.rect.bottom()))
+        });
+        self.front.ensure_size(width, height);
+        self.back.ensure_size(width, height);
+
         for (_id, state) in dirty {
-            render_zone(writer, state)?;
+            paint_zone(&mut self.back, state);
+        }
+
+        let mut frame = Vec::new();
+        if self.clear_pending {
+            frame.extend_from_slice(b"\x1b[2J");
+            self.front.fill_blank();
+            self.clear_pending = false;
         }
 
+        self.scroll_log_zones(&mut frame, dirty)?;
+        self.flush_diff(&mut frame)?;
+
         if let Some((row, col)) = self.settings.restore_cursor {
-            write!(writer, "\x1b[{};{}H", row + 1, col + 1)?;
+            write!(frame, "\x1b[{};{}H", row + 1, col + 1)?;
         }
 
         if let Some(visible) = self.settings.cursor_visible {
             if visible {
-                write!(writer, "\x1b[?25h")?;
+                write!(frame, "\x1b[?25h")?;
             } else {
-                write!(writer, "\x1b[?25l")?;
+                write!(frame, "\x1b[?25l")?;
             }
             self.settings.cursor_visible = None;
         }
 
+        if !frame.is_empty() {
+            if self.synchronized_output() {
+                let mut wrapped =
+                    Vec::with_capacity(frame.len() + SYNC_BEGIN.len() + SYNC_END.len());
+                wrapped.extend_from_slice(SYNC_BEGIN);
+                wrapped.extend_from_slice(&frame);
+                wrapped.extend_from_slice(SYNC_END);
+                frame = wrapped;
+            }
+            writer.write_all(&frame)?;
+        }
+
         writer.flush()?;
         Ok(())
     }
+
+    /// Resolve the synchronized-output setting, caching environment detection
+    /// until the setting changes.
+    fn synchronized_output(&mut self) -> bool {
+        let mode = self.settings.synchronized_output;
+        match self.sync_resolved {
+            Some((cached, enabled)) if cached == mode => enabled,
+            _ => {
+                let enabled = mode.resolve();
+                self.sync_resolved = Some((mode, enabled));
+                enabled
+            }
+        }
+    }
+
+    /// Scroll full-width log zones that follow their tail by the number of
+    /// rows their content moved up, and shift the front buffer to match.
+    fn scroll_log_zones(
+        &mut self,
+        writer: &mut impl Write,
+        dirty: &[(ZoneId, ZoneState)],
+    ) -> Result<()> {
+        for (_id, state) in dirty {
+            let rect = state.rect;
+            let following = state.log.as_ref().is_some_and(|log| log.is_following());
+            if !following || rect.x != 0 || rect.width != self.front.width() || rect.height < 2 {
+                continue;
+            }
+            if self.scrolled_by(rect, 0) {
+                continue;
+            }
+            let Some(lines) = (1..rect.height).find(|lines| self.scrolled_by(rect, *lines)) else {
+                continue;
+            };
+            write!(
+                writer,
+                "\x1b[{};{}r\x1b[{}S\x1b[r",
+                rect.y + 1,
+                rect.bottom(),
+                lines
+            )?;
+            self.front.scroll_up(rect.y, rect.bottom(), lines);
+        }
+        Ok(())
+    }
+
+    /// Whether the back buffer holds `rect` as the front buffer shows it
+    /// scrolled up by `lines` (the rows scrolled in are not compared).
+    fn scrolled_by(&self, rect: Rect, lines: u16) -> bool {
+        (rect.y..rect.bottom() - lines).all(|y| {
+            (rect.x..rect.right()).all(|x| {
+                let painted = self.back.get(x, y);
+                painted.is_some() && painted == self.front.get(x, y + lines)
+            })
+        })
+    }
+
+    /// Emit every back-buffer cell that differs from the front buffer, then
+    /// promote the painted cells into the front buffer.
+    fn flush_diff(&mut self, writer: &mut impl Write) -> Result<()> {
+        let mut cursor: Option<(u16, u16)> = None;
+        let mut active_style = Style::default();
+
+        for y in 0..self.back.height() {
+            for x in 0..self.back.width() {
+                let Some(cell) = self.back.take(x, y) else {
+                    continue;
+                };
+
+                if cell.is_continuation() {
+                    self.front.set(x, y, cell);
+                    continue;
+                }
+
+                let wide = display_width(&cell.symbol) > 1;
+                let unchanged = self.front.get(x, y) == Some(&cell)
+                    && (!wide || self.back.get(x + 1, y) == self.front.get(x + 1, y));
+                if unchanged {
+                    continue;
+                }
+
+                if cursor != Some((x, y)) {
+                    write!(writer, "\x1b[{};{}H", y + 1, x + 1)?;
+                }
+                if cell.style != active_style {
+                    if !active_style.is_default() {
+                        write!(writer, "\x1b[0m")?;
+                    }
+                    write!(writer, "{}", cell.style.to_sgr())?;
+                    active_style = cell.style;
+                }
+                write!(writer, "{}", cell.symbol)?;
+                cursor = Some((x + if wide { 2 } else { 1 }, y));
+                self.front.set(x, y, cell);
+            }
+        }
+
+        if !active_style.is_default() {
+            write!(writer, "\x1b[0m")?;
+        }
+
+        Ok(())
+    }
 }
 
-fn render_zone(writer: &mut impl Write, state: &ZoneState) -> Result<()> {
+/// Best-effort detection of terminals that implement synchronized updates.
+/// Terminals without support ignore the mode, so a false positive only costs
+/// a few bytes per frame.
+fn detect_synchronized_output() -> bool {
+    if let Ok(value) = std::env::var("ROOM_SYNC_OUTPUT") {
+        match value.to_ascii_lowercase().as_str() {
+            "1" | "true" | "on" | "yes" => return true,
+            "0" | "false" | "off" | "no" => return false,
+            _ => {}
+        }
+    }
+
+    let term_program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+    if matches!(
+        term_program.as_str(),
+        "WezTerm" | "iTerm.app" | "vscode" | "ghostty" | "contour" | "rio"
+    ) {
+        return true;
+    }
+
+    let term = std::env::var("TERM").unwrap_or_default();
+    ["kitty", "foot", "alacritty", "ghostty", "contour"]
+        .iter()
+        .any(|name| term.contains(name))
+}
+
+fn paint_zone(buffer: &mut FrameBuffer, state: &ZoneState) {
     let Rect {
         x,
         y,
@@ -71,119 +320,183 @@ fn render_zone(writer: &mut impl Write, state: &ZoneState) -> Result<()> {
     } = state.rect;
 
     if width == 0 || height == 0 {
-        return Ok(());
+        return;
     }
 
-    let mut rendered_lines = if state.is_pre_rendered {
-        state
-            .content
-            .lines()
-            .map(|line| line.to_string())
-            .collect::<Vec<_>>()
-    } else {
-        wrap_to_width(&state.content, width)
+    let format = state.format;
+    let lines = match &state.log {
+        Some(log) => log_lines(log, width, height, format.overflow),
+        None => zone_lines(state, width, format.overflow),
+    };
+    let visible = lines.len().min(height as usize) as u16;
+    let (skip, top) = match format.vertical_align {
+        VerticalAlign::Top => (0, 0),
+        VerticalAlign::Middle => (0, (height - visible) / 2),
+        VerticalAlign::Bottom => (lines.len() - visible as usize, height - visible),
     };
 
-    if rendered_lines.len() > height as usize {
-        rendered_lines.truncate(height as usize);
+    for row in 0..height {
+        let spans = row
+            .checked_sub(top)
+            .filter(|idx| *idx < visible)
+            .map(|idx| lines[skip + idx as usize].as_slice())
+            .unwrap_or(&[]);
+        paint_spans(buffer, x, y + row, width, spans, format.align);
     }
+}
 
-    while rendered_lines.len() < height as usize {
-        rendered_lines.push(String::new());
+/// Resolve zone content into styled lines.
+///
+/// Text zones may embed SGR sequences. They are parsed into spans before
+/// wrapping, so escapes never count towards the line width and are never split;
+/// the active style carries across wrapped lines the same way a terminal would
+/// keep it between writes. Pre-rendered zones keep their own line breaks and
+/// are clipped regardless of the overflow policy.
+fn zone_lines(state: &ZoneState, width: u16, overflow: Overflow) -> Vec<Vec<StyledSpan>> {
+    if let Some(styled) = &state.styled {
+        return layout_lines(styled, width, overflow);
     }
 
-    for (offset, line) in rendered_lines.iter_mut().enumerate() {
-        pad_line(line, width);
-        write!(writer, "\x1b[{};{}H", y + offset as u16 + 1, x + 1)?;
-        write!(writer, "{}", line)?;
+    let parsed = StyledText::from_ansi(&state.content);
+    if state.is_pre_rendered {
+        parsed.lines()
+    } else {
+        layout_lines(&parsed, width, overflow)
     }
+}
 
-    Ok(())
+/// Rows for a log zone: only lines up to the bottom of the viewport are laid
+/// out, newest first, until the zone height is filled.
+fn log_lines(log: &ZoneLog, width: u16, height: u16, overflow: Overflow) -> Vec<Vec<StyledSpan>> {
+    let mut rows = Vec::new();
+    for line in log.visible_lines(height as usize).rev() {
+        if rows.len() >= height as usize {
+            break;
+        }
+        let wrapped = layout_lines(&StyledText::from_ansi(line), width, overflow);
+        rows.extend(wrapped.into_iter().rev());
+    }
+    rows.truncate(height as usize);
+    rows.reverse();
+    rows
 }
 
-fn wrap_to_width(content: &str, width: u16) -> Vec<String> {
-    if width == 0 {
-        return Vec::new();
+/// Paint one line of spans into `buffer`, clipping at `width` and padding the
+/// rest of the row with unstyled blanks so styles never bleed past the line.
+fn paint_spans(
+    buffer: &mut FrameBuffer,
+    x: u16,
+    y: u16,
+    width: u16,
+    spans: &[StyledSpan],
+    align: HorizontalAlign,
+) {
+    let slack = width.saturating_sub(line_width(spans));
+    let offset = match align {
+        HorizontalAlign::Left => 0,
+        HorizontalAlign::Center => slack / 2,
+        HorizontalAlign::Right => slack,
+    };
+    for col in 0..offset {
+        buffer.set(x + col, y, Cell::blank());
     }
+    let mut col = offset;
 
-    let mut lines = Vec::new();
-    for raw in content.split('\n') {
-        if raw.is_empty() {
-            lines.push(String::new());
-            continue;
-        }
+    'spans: for span in spans {
+        for ch in span.text.chars() {
+            let ch_width = match ch.width() {
+                Some(w) => w as u16,
+                None => continue,
+            };
 
-        let mut current = String::new();
-        for ch in raw.chars() {
-            if current.is_empty() && ch == ' ' {
-                continue;
-            }
-            current.push(ch);
-            let display = display_width(&current) as u16;
-            if display > width {
-                current.pop();
-                if !current.is_empty() {
-                    let mut flushed = current.clone();
-                    if flushed.starts_with(' ') {
-                        flushed = flushed.trim_start().to_string();
+            if ch_width == 0 {
+                if col > 0 {
+                    let prev = if buffer
+                        .get(x + col - 1, y)
+                        .is_some_and(|cell| cell.is_continuation())
+                    {
+                        col.saturating_sub(2)
+                    } else {
+                        col - 1
+                    };
+                    if let Some(mut cell) = buffer.take(x + prev, y) {
+                        cell.symbol.push(ch);
+                        buffer.set(x + prev, y, cell);
                     }
-                    lines.push(flushed);
-                } else {
-                    // Character wider than available width, skip it.
-                    lines.push(String::new());
-                }
-                current.clear();
-                current.push(ch);
-            } else if display == width {
-                let mut flushed = current.clone();
-                if flushed.starts_with(' ') {
-                    flushed = flushed.trim_start().to_string();
                 }
-                lines.push(flushed);
-                current.clear();
+                continue;
             }
-        }
 
-        if !current.is_empty() {
-            let mut flushed = current;
-            if flushed.starts_with(' ') {
-                flushed = flushed.trim_start().to_string();
+            if col + ch_width > width {
+                break 'spans;
             }
-            lines.push(flushed);
-        }
-    }
 
-    lines
-}
-
-fn pad_line(line: &mut String, width: u16) {
-    let mut display = display_width(line) as u16;
-    while display < width {
-        line.push(' ');
-        display += 1;
+            buffer.set(x + col, y, Cell::new(ch, span.style));
+            if ch_width == 2 {
+                buffer.set(x + col + 1, y, Cell::continuation(span.style));
+            }
+            col += ch_width;
+        }
     }
 
-    if display > width {
-        // Truncate any overshoot caused by ANSI codes being stripped differently.
-        while (display_width(line) as u16) > width {
-            line.pop();
-        }
-        while (display_width(line) as u16) < width {
-            line.push(' ');
-        }
+    while col < width {
+        buffer.set(x + col, y, Cell::blank());
+        col += 1;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::VirtualTerminal;
     use crate::registry::ZoneRegistry;
+    use crate::render::wrap::wrap_styled;
+    use crate::style::ZoneFormat;
     use std::collections::HashMap;
 
+    fn test_renderer(sync: SyncOutput) -> AnsiRenderer {
+        AnsiRenderer::new(RendererSettings {
+            synchronized_output: sync,
+            ..RendererSettings::default()
+        })
+    }
+
     #[test]
     fn wrap_basic() {
-        let lines = wrap_to_width("hello world", 5);
-        assert_eq!(lines, vec!["hello".to_string(), "world".to_string()]);
+        let lines = wrap_styled(&StyledText::from("hello world"), 5);
+        assert_eq!(
+            lines,
+            vec![
+                vec![StyledSpan::plain("hello")],
+                vec![StyledSpan::plain("world")]
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_zones_wrap_ansi_without_counting_escapes() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(Size::new(5, 2));
+        renderer.mark_cleared();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("zone".to_string(), Rect::new(0, 0, 5, 2));
+        registry.sync_layout(&solved);
+        registry
+            .apply_content(
+                &"zone".to_string(),
+                "\x1b[31mhello world\x1b[0m".to_string(),
+            )
+            .unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut output = Vec::new();
+        renderer.render(&mut output, &dirty).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+        assert_eq!(
+            rendered,
+            "\u{1b}[1;1H\u{1b}[31mhello\u{1b}[2;1Hworld\u{1b}[0m"
+        );
     }
 
     #[test]
@@ -199,11 +512,237 @@ mod tests {
         let dirty = registry.take_dirty();
 
         let mut output = Vec::new();
-        let mut renderer = AnsiRenderer::with_default();
+        let mut renderer = test_renderer(SyncOutput::Disabled);
         renderer.render(&mut output, &dirty).unwrap();
 
-        let rendered = String::from_utf8(output).unwrap();
+        let rendered = String::from_utf8(output.clone()).unwrap();
         assert!(rendered.contains("\u{1b}[4;3Hhi"));
         assert!(rendered.contains("\u{1b}[5;3H"));
+
+        let mut terminal = VirtualTerminal::new(Size::new(8, 6));
+        terminal.write_all(&output).unwrap();
+        assert_eq!(terminal.row_text(3), "  hi");
+        assert_eq!(terminal.cell(4, 2), Some(&Cell::blank()));
+    }
+
+    fn render_zone(renderer: &mut AnsiRenderer, content: &str) -> String {
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("zone".to_string(), Rect::new(0, 0, 6, 1));
+        registry.sync_layout(&solved);
+        registry.take_dirty();
+        registry
+            .apply_content(&"zone".to_string(), content.to_string())
+            .unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut output = Vec::new();
+        renderer.render(&mut output, &dirty).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn renderer_only_emits_changed_cells() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        let first = render_zone(&mut renderer, "abcdef");
+        assert_eq!(first, "\u{1b}[1;1Habcdef");
+
+        let second = render_zone(&mut renderer, "abXdef");
+        assert_eq!(second, "\u{1b}[1;3HX");
+
+        let third = render_zone(&mut renderer, "abXdef");
+        assert_eq!(third, "");
+    }
+
+    #[test]
+    fn tail_appends_scroll_full_width_log_zones() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(Size::new(6, 3));
+        renderer.mark_cleared();
+        let zone = "log".to_string();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert(zone.clone(), Rect::new(0, 0, 6, 3));
+        registry.sync_layout(&solved);
+        let mut terminal = VirtualTerminal::new(Size::new(6, 3));
+        let mut append = |registry: &mut ZoneRegistry, line: &str| {
+            registry.append_log(&zone, line).unwrap();
+            let mut output = Vec::new();
+            renderer
+                .render(&mut output, &registry.take_dirty())
+                .unwrap();
+            terminal.write_all(&output).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        for line in ["one", "two", "three"] {
+            append(&mut registry, line);
+        }
+        let output = append(&mut registry, "four");
+        assert_eq!(output, "\u{1b}[1;3r\u{1b}[1S\u{1b}[r\u{1b}[3;1Hfour");
+        assert_eq!(terminal.screen_text(), "two\nthree\nfour");
+    }
+
+    #[test]
+    fn renderer_re_emits_after_invalidate() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        render_zone(&mut renderer, "abc");
+        renderer.invalidate();
+        let output = render_zone(&mut renderer, "abc");
+        assert_eq!(output, "\u{1b}[1;1Habc   ");
+    }
+
+    #[test]
+    fn renderer_skips_blank_cells_after_clear() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(Size::new(6, 1));
+        renderer.mark_cleared();
+        let output = render_zone(&mut renderer, "ab");
+        assert_eq!(output, "\u{1b}[1;1Hab");
+    }
+
+    #[test]
+    fn renderer_keeps_styles_per_cell() {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(Size::new(6, 1));
+        renderer.mark_cleared();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("zone".to_string(), Rect::new(0, 0, 6, 1));
+        registry.sync_layout(&solved);
+        registry
+            .apply_pre_rendered(&"zone".to_string(), "\x1b[31mred\x1b[0m!".to_string())
+            .unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut output = Vec::new();
+        renderer.render(&mut output, &dirty).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+        assert_eq!(rendered, "\u{1b}[1;1H\u{1b}[31mred\u{1b}[0m!");
+    }
+
+    #[test]
+    fn renderer_wraps_styled_zones() {
+        use crate::style::Color;
+
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(Size::new(3, 2));
+        renderer.mark_cleared();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("zone".to_string(), Rect::new(0, 0, 3, 2));
+        registry.sync_layout(&solved);
+        let green = Style::new().fg(Color::Green).bold();
+        registry
+            .apply_styled(
+                &"zone".to_string(),
+                StyledText::new()
+                    .span("ab", Style::default())
+                    .span("cde", green),
+            )
+            .unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut output = Vec::new();
+        renderer.render(&mut output, &dirty).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+        assert_eq!(rendered, "\u{1b}[1;1Hab\u{1b}[1;32mc\u{1b}[2;1Hde\u{1b}[0m");
+    }
+
+    fn render_formatted(size: Size, content: &str, format: ZoneFormat) -> String {
+        let mut renderer = test_renderer(SyncOutput::Disabled);
+        renderer.resize(size);
+        renderer.mark_cleared();
+        let zone = "zone".to_string();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert(zone.clone(), Rect::new(0, 0, size.width, size.height));
+        registry.sync_layout(&solved);
+        registry.apply_format(&zone, format).unwrap();
+        registry.apply_content(&zone, content.to_string()).unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut output = Vec::new();
+        renderer.render(&mut output, &dirty).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn renderer_honours_zone_format() {
+        let truncated = render_formatted(
+            Size::new(5, 3),
+            "hello world",
+            ZoneFormat::new()
+                .overflow(Overflow::Truncate)
+                .vertical_align(VerticalAlign::Bottom),
+        );
+        assert_eq!(truncated, "\u{1b}[3;1Hhell…");
+
+        let centered = render_formatted(
+            Size::new(6, 3),
+            "ab",
+            ZoneFormat::new()
+                .align(HorizontalAlign::Center)
+                .vertical_align(VerticalAlign::Middle),
+        );
+        assert_eq!(centered, "\u{1b}[2;3Hab");
+
+        let tail = render_formatted(
+            Size::new(4, 1),
+            "one\ntwo",
+            ZoneFormat::new()
+                .align(HorizontalAlign::Right)
+                .vertical_align(VerticalAlign::Bottom),
+        );
+        assert_eq!(tail, "\u{1b}[1;2Htwo");
+    }
+
+    #[derive(Default)]
+    struct CountingWriter {
+        writes: usize,
+        data: Vec<u8>,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes += 1;
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn renderer_wraps_frame_in_single_synchronized_write() {
+        let mut renderer = test_renderer(SyncOutput::Enabled);
+        renderer.resize(Size::new(2, 2));
+        renderer.request_clear();
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("a".to_string(), Rect::new(0, 0, 2, 1));
+        solved.insert("b".to_string(), Rect::new(0, 1, 2, 1));
+        registry.sync_layout(&solved);
+        registry
+            .apply_content(&"a".to_string(), "hi".to_string())
+            .unwrap();
+        registry
+            .apply_content(&"b".to_string(), "yo".to_string())
+            .unwrap();
+        let dirty = registry.take_dirty();
+
+        let mut writer = CountingWriter::default();
+        renderer.render(&mut writer, &dirty).unwrap();
+        assert_eq!(writer.writes, 1);
+        assert_eq!(
+            String::from_utf8(writer.data).unwrap(),
+            "\u{1b}[?2026h\u{1b}[2J\u{1b}[1;1Hhi\u{1b}[2;1Hyo\u{1b}[?2026l"
+        );
+
+        let mut idle = CountingWriter::default();
+        renderer.render(&mut idle, &[]).unwrap();
+        assert_eq!(idle.writes, 0);
     }
 }
@@ -1,5 +1,8 @@
 //! ANSI renderer orchestrator following Module Spec expectations.
 
+mod buffer;
 mod core;
+mod wrap;
 
-pub use core::{AnsiRenderer, RendererSettings};
+pub use buffer::{Cell, FrameBuffer};
+pub use core::{AnsiRenderer, RendererSettings, SyncOutput};
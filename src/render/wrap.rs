@@ -0,0 +1,249 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::style::{Overflow, Style, StyledSpan, StyledText};
+
+const ELLIPSIS: char = '…';
+
+/// Break styled text into display lines according to the zone's overflow policy.
+pub(crate) fn layout_lines(
+    text: &StyledText,
+    width: u16,
+    overflow: Overflow,
+) -> Vec<Vec<StyledSpan>> {
+    match overflow {
+        Overflow::WordWrap => wrap_words(text, width),
+        Overflow::CharWrap => wrap_styled(text, width),
+        Overflow::Truncate => text
+            .lines()
+            .iter()
+            .map(|line| truncate_line(line, width))
+            .collect(),
+        Overflow::Clip => text.lines(),
+    }
+}
+
+/// Total display width of a line of spans.
+pub(crate) fn line_width(line: &[StyledSpan]) -> u16 {
+    line.iter()
+        .flat_map(|span| span.text.chars())
+        .map(|ch| ch.width().unwrap_or(0) as u16)
+        .fold(0u16, u16::saturating_add)
+}
+
+/// Wrap styled text to `width` columns, keeping each character's style.
+///
+/// Follows the same rules as plain zone wrapping: explicit newlines start a new
+/// line, lines break at the column limit, and leading spaces on wrapped lines
+/// are dropped.
+pub(crate) fn wrap_styled(text: &StyledText, width: u16) -> Vec<Vec<StyledSpan>> {
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        if line.is_empty() {
+            lines.push(Vec::new());
+            continue;
+        }
+
+        let mut current = LineBuilder::default();
+        for span in &line {
+            for ch in span.text.chars() {
+                if current.is_empty() && ch == ' ' {
+                    continue;
+                }
+                let ch_width = ch.width().unwrap_or(0) as u16;
+                if ch_width > width {
+                    // Character wider than available width, skip it.
+                    continue;
+                }
+                if current.width + ch_width > width {
+                    lines.push(current.finish());
+                    if ch == ' ' {
+                        continue;
+                    }
+                }
+                current.push(ch, span.style, ch_width);
+                if current.width == width {
+                    lines.push(current.finish());
+                }
+            }
+        }
+
+        if !current.is_empty() {
+            lines.push(current.finish());
+        }
+    }
+
+    lines
+}
+
+/// Wrap styled text at word boundaries. Spaces between words that end up at a
+/// line break are dropped; words wider than the zone are broken by character.
+pub(crate) fn wrap_words(text: &StyledText, width: u16) -> Vec<Vec<StyledSpan>> {
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        if line.is_empty() {
+            lines.push(Vec::new());
+            continue;
+        }
+
+        let mut current = LineBuilder::default();
+        let mut pending_spaces: Vec<(char, Style, u16)> = Vec::new();
+        for word in split_words(&line) {
+            if word[0].0 == ' ' {
+                if !current.is_empty() {
+                    pending_spaces = word;
+                }
+                continue;
+            }
+
+            let word_width: u16 = word.iter().map(|(_, _, w)| *w).sum();
+            let spaces_width: u16 = pending_spaces.iter().map(|(_, _, w)| *w).sum();
+            if !current.is_empty() && current.width + spaces_width + word_width <= width {
+                for (ch, style, w) in pending_spaces.drain(..) {
+                    current.push(ch, style, w);
+                }
+            } else if !current.is_empty() {
+                lines.push(current.finish());
+            }
+            pending_spaces.clear();
+
+            for (ch, style, ch_width) in word {
+                if ch_width > width {
+                    continue;
+                }
+                if current.width + ch_width > width {
+                    lines.push(current.finish());
+                }
+                current.push(ch, style, ch_width);
+            }
+        }
+
+        if !current.is_empty() {
+            lines.push(current.finish());
+        }
+    }
+
+    lines
+}
+
+/// Group a line into alternating runs of spaces and non-space characters.
+fn split_words(line: &[StyledSpan]) -> Vec<Vec<(char, Style, u16)>> {
+    let mut words: Vec<Vec<(char, Style, u16)>> = Vec::new();
+    for span in line {
+        for ch in span.text.chars() {
+            let entry = (ch, span.style, ch.width().unwrap_or(0) as u16);
+            match words.last_mut() {
+                Some(word) if (word[0].0 == ' ') == (ch == ' ') => word.push(entry),
+                _ => words.push(vec![entry]),
+            }
+        }
+    }
+    words
+}
+
+/// Cut a line to `width` columns, ending it with `…` when anything was removed.
+pub(crate) fn truncate_line(line: &[StyledSpan], width: u16) -> Vec<StyledSpan> {
+    if line_width(line) <= width {
+        return line.to_vec();
+    }
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut current = LineBuilder::default();
+    let mut ellipsis_style = Style::default();
+    'spans: for span in line {
+        for ch in span.text.chars() {
+            let ch_width = ch.width().unwrap_or(0) as u16;
+            ellipsis_style = span.style;
+            if current.width + ch_width > width - 1 {
+                break 'spans;
+            }
+            current.push(ch, span.style, ch_width);
+        }
+    }
+    current.push(ELLIPSIS, ellipsis_style, 1);
+    current.finish()
+}
+
+#[derive(Default)]
+struct LineBuilder {
+    spans: Vec<StyledSpan>,
+    width: u16,
+}
+
+impl LineBuilder {
+    fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    fn push(&mut self, ch: char, style: Style, width: u16) {
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push(ch),
+            _ => self.spans.push(StyledSpan::new(ch, style)),
+        }
+        self.width += width;
+    }
+
+    fn finish(&mut self) -> Vec<StyledSpan> {
+        self.width = 0;
+        std::mem::take(&mut self.spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn wrap_words_breaks_at_spaces() {
+        let text = StyledText::from("the quick brown fox");
+        let lines: Vec<String> = wrap_words(&text, 10)
+            .iter()
+            .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+            .collect();
+        assert_eq!(lines, vec!["the quick", "brown fox"]);
+
+        let long = StyledText::from("abcdefgh ij");
+        let lines: Vec<String> = wrap_words(&long, 3)
+            .iter()
+            .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+            .collect();
+        assert_eq!(lines, vec!["abc", "def", "gh", "ij"]);
+    }
+
+    #[test]
+    fn truncate_line_appends_ellipsis_in_cut_style() {
+        let bold = Style::new().bold();
+        let line = vec![StyledSpan::plain("ab"), StyledSpan::new("cdef", bold)];
+        assert_eq!(
+            truncate_line(&line, 4),
+            vec![StyledSpan::plain("ab"), StyledSpan::new("c…", bold)]
+        );
+        assert_eq!(truncate_line(&line, 6), line);
+    }
+
+    #[test]
+    fn wrap_styled_splits_spans_across_lines() {
+        let red = Style::new().fg(Color::Red);
+        let text = StyledText::new()
+            .span("hel", Style::default())
+            .span("lo world", red);
+        let lines = wrap_styled(&text, 5);
+        assert_eq!(
+            lines,
+            vec![
+                vec![StyledSpan::plain("hel"), StyledSpan::new("lo", red)],
+                vec![StyledSpan::new("world", red)],
+            ]
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 use crate::logging::{LogLevel, Logger};
 use crate::metrics::RuntimeMetrics;
-use crate::{Result, display_width};
+use crate::{Overflow, Result, ZoneFormat, display_width};
 
 use super::diagnostics::{LifecycleLoggerPlugin, MetricsSnapshotPlugin};
 use super::focus::{FocusController, ensure_focus_registry};
@@ -112,6 +112,14 @@ pub struct InputSharedState {
 
 pub type SharedInputState = Arc<RwLock<InputSharedState>>;
 
+/// Published by the default input plugin when a line is submitted; subscribe
+/// with `event.custom::<InputSubmitted>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSubmitted {
+    pub zone: String,
+    pub text: String,
+}
+
 pub fn ensure_input_state(ctx: &RuntimeContext<'_>) -> Result<SharedInputState> {
     ctx.shared_init::<RwLock<InputSharedState>, _>(|| RwLock::new(InputSharedState::default()))
         .map_err(map_shared_err)
@@ -179,6 +187,10 @@ impl DefaultInputPlugin {
             guard.submission_count += 1;
             guard.last_submission = Some(text.to_string());
         }
+        ctx.publish(InputSubmitted {
+            zone: self.zone_id.clone(),
+            text: text.to_string(),
+        });
 
         self.buffer.clear();
         ctx.request_render();
@@ -314,6 +326,10 @@ impl RoomPlugin for DefaultStatusBarPlugin {
     }
 
     fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+        ctx.set_zone_format(
+            &self.zone_id,
+            ZoneFormat::new().overflow(Overflow::Truncate),
+        );
         ctx.set_zone(&self.zone_id, self.status_line(ctx));
         Ok(())
     }
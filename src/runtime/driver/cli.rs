@@ -1,6 +1,7 @@
 use std::io::{self, Write};
 
 use crossterm::cursor::{Hide, Show};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::execute;
 use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
 use thiserror::Error;
@@ -24,11 +25,22 @@ pub enum CliDriverError {
 /// runtime loop without copying boilerplate.
 pub struct CliDriver {
     runtime: RoomRuntime,
+    mouse_capture: bool,
 }
 
 impl CliDriver {
     pub fn new(runtime: RoomRuntime) -> Self {
-        Self { runtime }
+        Self {
+            runtime,
+            mouse_capture: false,
+        }
+    }
+
+    /// Ask the terminal to report mouse events (clicks, drags, wheel), which
+    /// reach plugins as `RuntimeEvent::Mouse`.
+    pub fn with_mouse_capture(mut self, enabled: bool) -> Self {
+        self.mouse_capture = enabled;
+        self
     }
 
     pub fn run(mut self) -> DriverResult<()> {
@@ -54,10 +66,16 @@ impl CliDriver {
     fn enter(&self, stdout: &mut impl Write) -> DriverResult<()> {
         terminal::enable_raw_mode().map_err(|err| CliDriverError::Terminal(err.to_string()))?;
         execute!(stdout, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
+        if self.mouse_capture {
+            execute!(stdout, EnableMouseCapture)?;
+        }
         Ok(())
     }
 
     fn exit(&self, stdout: &mut impl Write) {
+        if self.mouse_capture {
+            execute!(stdout, DisableMouseCapture).ok();
+        }
         execute!(stdout, Show, LeaveAlternateScreen).ok();
         terminal::disable_raw_mode().ok();
     }
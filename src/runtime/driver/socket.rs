@@ -5,6 +5,7 @@ use std::time::Duration;
 use serde::de::DeserializeOwned;
 use thiserror::Error;
 
+use crate::runtime::external::Input;
 use crate::{LayoutError, RoomRuntime, RuntimeConfig, RuntimeEvent, Size};
 
 pub type DriverResult<T> = std::result::Result<T, SocketDriverError>;
@@ -72,30 +73,54 @@ impl<S: SocketStrategy> SocketDriver<S> {
             stream.set_nodelay(true).ok();
             self.runtime.resize(self.initial_size)?;
 
-            let inbound = BufReader::new(stream.try_clone()?);
-            for line in inbound.lines() {
-                let line = line?;
-                if line.trim().is_empty() {
-                    continue;
-                }
-                let payload: S::Inbound = serde_json::from_str(&line)
-                    .map_err(|err| SocketDriverError::Decode(err.to_string()))?;
-                let events = self.strategy.decode(payload)?;
+            // Lines are read on a background thread so that events from
+            // `RoomRuntime::event_sender` are not held back until the client
+            // sends something.
+            let mut inbound = BufReader::new(stream.try_clone()?);
+            self.runtime.spawn_input_reader(move || {
+                let mut line = String::new();
+                Ok(match inbound.read_line(&mut line)? {
+                    0 => Input::Closed,
+                    _ => Input::Line(line),
+                })
+            });
+            loop {
                 let mut writer = stream.try_clone()?;
-                self.runtime.run_scripted(&mut writer, events)?;
-                if let Some(outbound) = self.strategy.after_events(&self.runtime) {
-                    let encoded = self.strategy.encode(outbound)?;
-                    if !encoded.is_empty() {
-                        writer.write_all(encoded.as_bytes())?;
-                        writer.write_all(b"\n")?;
-                        writer.flush()?;
+                let payload = match self.runtime.wait_input() {
+                    None => {
+                        if self.runtime.pump_external(&mut writer)? {
+                            self.respond(&mut writer)?;
+                        }
+                        continue;
                     }
+                    Some(Ok(Input::Line(payload))) => payload,
+                    Some(Ok(_)) => break,
+                    Some(Err(err)) => return Err(err.into()),
+                };
+                if !payload.trim().is_empty() {
+                    let payload: S::Inbound = serde_json::from_str(&payload)
+                        .map_err(|err| SocketDriverError::Decode(err.to_string()))?;
+                    let events = self.strategy.decode(payload)?;
+                    self.runtime.run_scripted(&mut writer, events)?;
+                    self.respond(&mut writer)?;
                 }
             }
             break;
         }
         Ok(())
     }
+
+    fn respond(&self, writer: &mut impl Write) -> DriverResult<()> {
+        if let Some(outbound) = self.strategy.after_events(&self.runtime) {
+            let encoded = self.strategy.encode(outbound)?;
+            if !encoded.is_empty() {
+                writer.write_all(encoded.as_bytes())?;
+                writer.write_all(b"\n")?;
+                writer.flush()?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Default stub strategy: simple JSON events (key/resize/tick/paste), no outbound frames.
@@ -0,0 +1,262 @@
+use std::any::{Any, type_name};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::Event as CrosstermEvent;
+use thiserror::Error;
+
+/// Event delivered to plugins as `RuntimeEvent::Custom`, either injected
+/// from another thread or published by a plugin. The optional payload can be
+/// any `Send + Sync` value; plugins read it back with [`CustomEvent::payload`].
+#[derive(Clone)]
+pub struct CustomEvent {
+    pub name: String,
+    payload: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl CustomEvent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            payload: None,
+        }
+    }
+
+    pub fn with_payload<T>(name: impl Into<String>, payload: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            payload: Some(Arc::new(payload)),
+        }
+    }
+
+    /// Event named after the payload's type, as sent by
+    /// `RuntimeContext::publish` and [`EventSender::publish`].
+    pub fn typed<T>(payload: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        Self::with_payload(type_name::<T>(), payload)
+    }
+
+    /// The payload, if there is one of type `T`.
+    pub fn payload<T: 'static>(&self) -> Option<&T> {
+        self.payload.as_deref()?.downcast_ref()
+    }
+}
+
+impl fmt::Debug for CustomEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomEvent")
+            .field("name", &self.name)
+            .field("payload", &self.payload.is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("runtime is gone; event `{0}` was dropped")]
+pub struct RuntimeClosed(pub String);
+
+/// Cloneable handle for feeding [`CustomEvent`]s to a runtime from other
+/// threads, obtained from `RoomRuntime::event_sender`.
+#[derive(Clone)]
+pub struct EventSender {
+    tx: Sender<Message>,
+}
+
+impl EventSender {
+    pub fn send(&self, event: CustomEvent) -> Result<(), RuntimeClosed> {
+        self.tx
+            .send(Message::External(event))
+            .map_err(|err| match err.0 {
+                Message::External(event) => RuntimeClosed(event.name),
+                Message::Input(_) => unreachable!("senders only send external events"),
+            })
+    }
+
+    /// Send an event without payload.
+    pub fn notify(&self, name: impl Into<String>) -> Result<(), RuntimeClosed> {
+        self.send(CustomEvent::new(name))
+    }
+
+    /// Send `event` as a [`CustomEvent::typed`] event.
+    pub fn publish<T>(&self, event: T) -> Result<(), RuntimeClosed>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.send(CustomEvent::typed(event))
+    }
+}
+
+/// Input read on a background thread while the runtime waits for external
+/// events, so that one blocking wait covers both.
+#[derive(Debug)]
+pub(crate) enum Input {
+    Terminal(CrosstermEvent),
+    Line(String),
+    /// The input source reached its end; the reader has stopped.
+    Closed,
+}
+
+pub(crate) enum Message {
+    External(CustomEvent),
+    Input(io::Result<Input>),
+}
+
+/// Receiving end kept by the runtime; created with the first sender.
+pub(crate) struct ExternalEvents {
+    tx: Sender<Message>,
+    rx: Receiver<Message>,
+    received: VecDeque<CustomEvent>,
+    input: VecDeque<io::Result<Input>>,
+    reading: bool,
+}
+
+impl ExternalEvents {
+    pub(crate) fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            tx,
+            rx,
+            received: VecDeque::new(),
+            input: VecDeque::new(),
+            reading: false,
+        }
+    }
+
+    pub(crate) fn sender(&self) -> EventSender {
+        EventSender {
+            tx: self.tx.clone(),
+        }
+    }
+
+    /// Start a thread that feeds `read` results into the channel until it
+    /// fails, closes, or the runtime is dropped. Does nothing while a reader
+    /// is still running.
+    pub(crate) fn spawn_reader<F>(&mut self, mut read: F)
+    where
+        F: FnMut() -> io::Result<Input> + Send + 'static,
+    {
+        if self.reading {
+            return;
+        }
+        self.reading = true;
+        let tx = self.tx.clone();
+        thread::spawn(move || {
+            loop {
+                let input = read();
+                let done = !matches!(input, Ok(Input::Terminal(_) | Input::Line(_)));
+                if tx.send(Message::Input(input)).is_err() || done {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Block until input is waiting, or `timeout` passes. With `events` set,
+    /// waiting external events end the wait too. Returns whether input is
+    /// waiting.
+    pub(crate) fn wait(&mut self, timeout: Option<Duration>, events: bool) -> bool {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        self.collect();
+        while self.input.is_empty() && (!events || self.received.is_empty()) {
+            // `self.tx` keeps the channel open, so this only fails on timeout.
+            let message = match deadline {
+                Some(deadline) => self
+                    .rx
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    .ok(),
+                None => self.rx.recv().ok(),
+            };
+            let Some(message) = message else {
+                break;
+            };
+            self.stash(message);
+        }
+        !self.input.is_empty()
+    }
+
+    /// Next input from the reader, in the order it was read.
+    pub(crate) fn next_input(&mut self) -> Option<io::Result<Input>> {
+        self.collect();
+        let input = self.input.pop_front()?;
+        if !matches!(input, Ok(Input::Terminal(_) | Input::Line(_))) {
+            self.reading = false;
+        }
+        Some(input)
+    }
+
+    pub(crate) fn drain(&mut self) -> Vec<CustomEvent> {
+        self.collect();
+        self.received.drain(..).collect()
+    }
+
+    fn collect(&mut self) {
+        while let Ok(message) = self.rx.try_recv() {
+            self.stash(message);
+        }
+    }
+
+    fn stash(&mut self, message: Message) {
+        match message {
+            Message::External(event) => self.received.push_back(event),
+            Message::Input(input) => self.input.push_back(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_cross_threads_with_payloads() {
+        let mut external = ExternalEvents::new();
+        let sender = external.sender();
+        std::thread::spawn(move || {
+            sender.notify("tick").unwrap();
+            sender
+                .send(CustomEvent::with_payload("line", "tail -f".to_string()))
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let events = external.drain();
+        assert_eq!(events[0].name, "tick");
+        assert_eq!(events[0].payload::<String>(), None);
+        assert_eq!(events[1].payload::<String>().unwrap(), "tail -f");
+        assert!(external.drain().is_empty());
+    }
+
+    #[test]
+    fn waits_wake_up_for_input_and_external_events() {
+        let mut external = ExternalEvents::new();
+        let sender = external.sender();
+        assert!(!external.wait(Some(Duration::ZERO), true));
+
+        let mut lines = vec!["second".to_string(), "first".to_string()];
+        external.spawn_reader(move || Ok(lines.pop().map_or(Input::Closed, Input::Line)));
+        let mut read = Vec::new();
+        while external.wait(None, true) {
+            match external.next_input() {
+                Some(Ok(Input::Line(line))) => read.push(line),
+                Some(Ok(Input::Closed)) => break,
+                other => panic!("unexpected input {other:?}"),
+            }
+        }
+        assert_eq!(read, ["first", "second"]);
+
+        std::thread::spawn(move || sender.notify("done").unwrap());
+        assert!(!external.wait(None, true));
+        assert_eq!(external.drain()[0].name, "done");
+    }
+}
@@ -0,0 +1,156 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use blake3::Hash;
+
+use crate::{LayoutSpec, Result};
+
+use super::{EventFlow, RoomPlugin, RuntimeContext, RuntimeError, RuntimeEvent};
+
+/// Reloads the runtime layout whenever a layout file changes on disk.
+///
+/// The file is polled on `Tick` events (every 500ms by default) and compared
+/// by content hash, so it works on any filesystem without a watcher. The
+/// runtime is assumed to start from the file's current contents; only later
+/// edits are applied. A file that fails to parse or solve is reported as a
+/// recoverable `layout_reload` error and the previous layout stays active.
+pub struct LayoutReloadPlugin {
+    path: PathBuf,
+    interval: Duration,
+    since_poll: Duration,
+    last_hash: Option<Hash>,
+}
+
+impl LayoutReloadPlugin {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            interval: Duration::from_millis(500),
+            since_poll: Duration::ZERO,
+            last_hash: None,
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_if_changed(&mut self) -> Option<std::io::Result<String>> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) => return Some(Err(err)),
+        };
+        let hash = blake3::hash(contents.as_bytes());
+        if self.last_hash == Some(hash) {
+            return None;
+        }
+        self.last_hash = Some(hash);
+        Some(Ok(contents))
+    }
+
+    fn reload(&mut self, ctx: &mut RuntimeContext<'_>) {
+        let result = match self.read_if_changed() {
+            None => return,
+            Some(Ok(contents)) => LayoutSpec::parse_file(&self.path, &contents),
+            Some(Err(err)) => Err(err.into()),
+        };
+        match result {
+            Ok(spec) => ctx.set_layout(spec),
+            Err(err) => ctx.report_error(RuntimeError {
+                category: "layout_reload".to_string(),
+                source: Some(self.path.display().to_string()),
+                message: err.to_string(),
+                recoverable: true,
+            }),
+        }
+    }
+}
+
+impl RoomPlugin for LayoutReloadPlugin {
+    fn name(&self) -> &str {
+        "room::layout_reload"
+    }
+
+    fn init(&mut self, _ctx: &mut RuntimeContext<'_>) -> Result<()> {
+        self.since_poll = Duration::ZERO;
+        self.last_hash = std::fs::read(&self.path)
+            .ok()
+            .map(|contents| blake3::hash(&contents));
+        Ok(())
+    }
+
+    fn on_event(
+        &mut self,
+        ctx: &mut RuntimeContext<'_>,
+        event: &RuntimeEvent,
+    ) -> Result<EventFlow> {
+        if let RuntimeEvent::Tick { elapsed } = event {
+            self.since_poll += *elapsed;
+            if self.since_poll >= self.interval {
+                self.since_poll = Duration::ZERO;
+                self.reload(ctx);
+            }
+        }
+        Ok(EventFlow::Continue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnsiRenderer, RoomRuntime, Size, VirtualTerminal};
+
+    const SINGLE: &str = r#"{"type": "grid", "cols": [{"flex": 1}], "rows": [{"flex": 1}],
+        "template": "main"}"#;
+    const SPLIT: &str = r#"{"type": "grid", "cols": [{"fixed": 4}, {"flex": 1}],
+        "rows": [{"flex": 1}], "template": "side main"}"#;
+
+    struct MainPlugin;
+
+    impl RoomPlugin for MainPlugin {
+        fn name(&self) -> &str {
+            "main"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone("main", "Main");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reloads_layout_when_file_changes() {
+        let path = std::env::temp_dir().join(format!("room-layout-{}.json", std::process::id()));
+        std::fs::write(&path, SINGLE).unwrap();
+
+        let layout = LayoutSpec::load(&path).unwrap();
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(12, 1)).unwrap();
+        runtime.register_plugin(MainPlugin);
+        runtime.register_plugin(LayoutReloadPlugin::new(&path).with_interval(Duration::ZERO));
+
+        let tick = || RuntimeEvent::Tick {
+            elapsed: Duration::from_millis(10),
+        };
+        let events = (0..3).map(|step| {
+            match step {
+                1 => std::fs::write(&path, SPLIT).unwrap(),
+                2 => std::fs::write(&path, "{ not json").unwrap(),
+                _ => {}
+            }
+            tick()
+        });
+
+        let mut terminal = VirtualTerminal::new(Size::new(12, 1));
+        runtime.run_scripted(&mut terminal, events).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(terminal.frames().first().map(String::as_str), Some("Main"));
+        assert_eq!(terminal.screen_text(), "    Main");
+    }
+}
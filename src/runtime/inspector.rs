@@ -0,0 +1,274 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crossterm::event::KeyCode;
+
+use crate::{Color, Rect, Result, Style, StyledText};
+
+use super::{EventFlow, RoomPlugin, RuntimeContext, RuntimeEvent};
+
+const OVERLAY_PREFIX: &str = "room::inspector/";
+
+/// Debug overlay that outlines every solved zone with its id, size and the
+/// sizing rule the layout applied.
+///
+/// The hotkey (`F12` by default) toggles the overlay. Only the outline cells
+/// are covered, so zone content stays readable underneath. Zones collapsed to
+/// zero width or height cannot be outlined and are listed on the bottom row
+/// instead. The outlines follow resizes and layout swaps while shown.
+pub struct LayoutInspectorPlugin {
+    hotkey: KeyCode,
+    z_index: i32,
+    active: bool,
+    stale: bool,
+    inspected: HashMap<String, Rect>,
+    overlays: BTreeSet<String>,
+}
+
+impl LayoutInspectorPlugin {
+    pub fn new() -> Self {
+        Self {
+            hotkey: KeyCode::F(12),
+            z_index: 10_000,
+            active: false,
+            stale: false,
+            inspected: HashMap::new(),
+            overlays: BTreeSet::new(),
+        }
+    }
+
+    pub fn with_hotkey(mut self, hotkey: KeyCode) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
+
+    /// Base z-index of the outline overlays; smaller zones stack above larger ones.
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn label(ctx: &RuntimeContext<'_>, zone: &str, rect: &Rect) -> String {
+        match ctx.zone_constraint(zone) {
+            Some(constraint) => format!("{zone} {}x{} {constraint}", rect.width, rect.height),
+            None => format!("{zone} {}x{}", rect.width, rect.height),
+        }
+    }
+
+    fn open(
+        &mut self,
+        ctx: &mut RuntimeContext<'_>,
+        id: String,
+        rect: Rect,
+        z_index: i32,
+        text: String,
+    ) {
+        let style = Style::default().fg(Color::BrightMagenta);
+        ctx.open_overlay(id.clone(), rect, z_index);
+        ctx.set_zone_styled(id.clone(), StyledText::new().span(text, style));
+        self.overlays.insert(id);
+    }
+
+    fn outline(&mut self, ctx: &mut RuntimeContext<'_>, zone: &str, rect: Rect, z_index: i32) {
+        let width = rect.width as usize;
+        let mut top = vec!['─'; width];
+        top[0] = '┌';
+        if width > 1 {
+            top[width - 1] = '┐';
+        }
+        let label = format!(" {} ", Self::label(ctx, zone, &rect));
+        for (cell, ch) in top[1..width.saturating_sub(1).max(1)]
+            .iter_mut()
+            .zip(label.chars())
+        {
+            *cell = ch;
+        }
+        let id = |edge: &str| format!("{OVERLAY_PREFIX}{zone}/{edge}");
+        let top_rect = Rect::new(rect.x, rect.y, rect.width, 1);
+        self.open(ctx, id("top"), top_rect, z_index, top.into_iter().collect());
+
+        if rect.height >= 2 {
+            let mut bottom = vec!['─'; width];
+            bottom[0] = '└';
+            if width > 1 {
+                bottom[width - 1] = '┘';
+            }
+            let bottom_rect = Rect::new(rect.x, rect.y + rect.height - 1, rect.width, 1);
+            self.open(
+                ctx,
+                id("bottom"),
+                bottom_rect,
+                z_index,
+                bottom.into_iter().collect(),
+            );
+        }
+
+        if rect.height >= 3 {
+            let side = vec!["│"; rect.height as usize - 2].join("\n");
+            let left_rect = Rect::new(rect.x, rect.y + 1, 1, rect.height - 2);
+            self.open(ctx, id("left"), left_rect, z_index, side.clone());
+            if rect.width >= 2 {
+                let right_rect = Rect::new(rect.x + rect.width - 1, rect.y + 1, 1, rect.height - 2);
+                self.open(ctx, id("right"), right_rect, z_index, side);
+            }
+        }
+    }
+
+    fn draw(&mut self, ctx: &mut RuntimeContext<'_>) {
+        let previous = std::mem::take(&mut self.overlays);
+
+        // Larger zones first, so nested zones get the higher z-index.
+        let mut zones: Vec<(String, Rect)> = self
+            .inspected
+            .iter()
+            .map(|(zone, rect)| (zone.clone(), *rect))
+            .collect();
+        zones.sort_by(|(a_id, a), (b_id, b)| {
+            let area = |rect: &Rect| rect.width as u32 * rect.height as u32;
+            area(b).cmp(&area(a)).then_with(|| a_id.cmp(b_id))
+        });
+
+        let mut collapsed = Vec::new();
+        let mut z_index = self.z_index;
+        for (zone, rect) in &zones {
+            if rect.width == 0 || rect.height == 0 {
+                collapsed.push(Self::label(ctx, zone, rect));
+                continue;
+            }
+            self.outline(ctx, zone, *rect, z_index);
+            z_index += 1;
+        }
+
+        let screen_width = zones.iter().map(|(_, rect)| rect.x + rect.width).max();
+        let screen_height = zones.iter().map(|(_, rect)| rect.y + rect.height).max();
+        if let (Some(width), Some(height)) = (screen_width, screen_height)
+            && !collapsed.is_empty()
+            && width > 0
+            && height > 0
+        {
+            let legend = Rect::new(0, height - 1, width, 1);
+            let text = format!("collapsed: {}", collapsed.join(", "));
+            self.open(
+                ctx,
+                format!("{OVERLAY_PREFIX}collapsed"),
+                legend,
+                z_index,
+                text,
+            );
+        }
+
+        for stale in previous.difference(&self.overlays) {
+            ctx.close_overlay(stale.clone());
+        }
+    }
+
+    fn hide(&mut self, ctx: &mut RuntimeContext<'_>) {
+        for overlay in std::mem::take(&mut self.overlays) {
+            ctx.close_overlay(overlay);
+        }
+        self.inspected.clear();
+    }
+}
+
+impl Default for LayoutInspectorPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoomPlugin for LayoutInspectorPlugin {
+    fn name(&self) -> &str {
+        "room::layout_inspector"
+    }
+
+    fn on_event(
+        &mut self,
+        ctx: &mut RuntimeContext<'_>,
+        event: &RuntimeEvent,
+    ) -> Result<EventFlow> {
+        match event {
+            RuntimeEvent::Key(key) if key.code == self.hotkey => {
+                self.active = !self.active;
+                if self.active {
+                    self.stale = true;
+                } else {
+                    self.hide(ctx);
+                }
+                ctx.request_render();
+                Ok(EventFlow::Consumed)
+            }
+            _ => Ok(EventFlow::Continue),
+        }
+    }
+
+    fn before_render(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+        let rects: HashMap<String, Rect> = ctx
+            .rects()
+            .iter()
+            .filter(|(zone, _)| !zone.starts_with(OVERLAY_PREFIX))
+            .map(|(zone, rect)| (zone.clone(), *rect))
+            .collect();
+        if self.stale || rects != self.inspected {
+            self.stale = false;
+            self.inspected = rects;
+            self.draw(ctx);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AnsiRenderer, Constraint, Direction, LayoutNode, LayoutTree, RoomRuntime, Size,
+        VirtualTerminal,
+    };
+    use crossterm::event::{KeyEvent, KeyModifiers};
+
+    fn layout() -> LayoutTree {
+        LayoutTree::new(LayoutNode::container(
+            "root",
+            Direction::Row,
+            vec![
+                Constraint::Fixed(14),
+                Constraint::Fixed(0),
+                Constraint::Flex(1),
+            ],
+            vec![
+                LayoutNode::leaf("nav"),
+                LayoutNode::leaf("gone"),
+                LayoutNode::leaf("main"),
+            ],
+        ))
+    }
+
+    #[test]
+    fn outlines_zones_and_lists_collapsed_ones() {
+        let mut runtime =
+            RoomRuntime::new(layout(), AnsiRenderer::with_default(), Size::new(30, 4)).unwrap();
+        runtime.register_plugin(LayoutInspectorPlugin::new());
+
+        let f12 = || RuntimeEvent::Key(KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE));
+        let mut terminal = VirtualTerminal::new(Size::new(30, 4));
+        runtime.run_scripted(&mut terminal, [f12(), f12()]).unwrap();
+
+        let shown = terminal
+            .frames()
+            .iter()
+            .find(|frame| frame.contains("collapsed"))
+            .expect("inspector frame");
+        let rows: Vec<&str> = shown.lines().collect();
+        assert_eq!(rows[0], "┌ nav 14x4 Fi┐┌ main 16x4 Fle┐");
+        assert_eq!(rows[1], "│            ││              │");
+        assert_eq!(rows[3], "collapsed: gone 0x4 Fixed(0)");
+
+        assert_eq!(terminal.screen_text().trim(), "");
+    }
+}
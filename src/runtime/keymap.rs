@@ -0,0 +1,598 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use serde::Deserialize;
+
+use crate::error::{LayoutError, Result};
+
+use super::RuntimeContext;
+use super::shared_state::SharedStateError;
+
+/// Mode a new keymap starts in.
+pub const DEFAULT_MODE: &str = "normal";
+
+/// How long a half-typed sequence waits for its next key by default.
+pub const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// One key press with its modifiers, written `ctrl-x`, `C-x`, `alt-enter`,
+/// `M-f`, `shift-tab`, `F5` or a single character. Shift is implied by the
+/// case of character keys, so `G` and `shift-g` are the same chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let (code, modifiers) = match code {
+            KeyCode::Char(ch) if modifiers.contains(KeyModifiers::SHIFT) => (
+                KeyCode::Char(ch.to_ascii_uppercase()),
+                modifiers - KeyModifiers::SHIFT,
+            ),
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+            }
+            KeyCode::BackTab => (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT),
+            other => (other, modifiers),
+        };
+        Self { code, modifiers }
+    }
+
+    /// Parse a space separated sequence such as `g g` or `ctrl-x ctrl-s`.
+    pub fn parse_sequence(keys: &str) -> Result<Vec<KeyChord>> {
+        let chords = keys
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if chords.is_empty() {
+            return Err(LayoutError::Keymap("empty key sequence".to_string()));
+        }
+        Ok(chords)
+    }
+}
+
+impl From<&KeyEvent> for KeyChord {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = LayoutError;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let invalid = || LayoutError::Keymap(format!("invalid key `{text}`"));
+        // A trailing separator is the key itself, as in `ctrl--` or `C-+`.
+        let last = text.char_indices().last().map_or(0, |(idx, _)| idx);
+        let split = text[..last].rfind(['-', '+']).map_or(0, |idx| idx + 1);
+        let (prefix, key) = text.split_at(split);
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in prefix.split(['-', '+']).filter(|name| !name.is_empty()) {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "c" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "m" | "a" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(ch), None) => KeyCode::Char(ch),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" | "return" | "ret" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" | "bs" => KeyCode::Backspace,
+                "space" | "spc" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" | "pgup" => KeyCode::PageUp,
+                "pagedown" | "pgdn" => KeyCode::PageDown,
+                "insert" | "ins" => KeyCode::Insert,
+                "delete" | "del" => KeyCode::Delete,
+                name => {
+                    let number = name.strip_prefix('f').and_then(|n| n.parse().ok());
+                    KeyCode::F(number.ok_or_else(invalid)?)
+                }
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("shift-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(ch) => write!(f, "{ch}"),
+            KeyCode::F(number) => write!(f, "f{number}"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::Delete => f.write_str("delete"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// Where a binding applies. Unset fields match anything; when several
+/// bindings share a sequence the most specific one wins (zone, then screen,
+/// then mode), and among equals the one bound last.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct KeyScope {
+    #[serde(default)]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub screen: Option<String>,
+    #[serde(default)]
+    pub zone: Option<String>,
+}
+
+impl KeyScope {
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    pub fn screen(mut self, screen: impl Into<String>) -> Self {
+        self.screen = Some(screen.into());
+        self
+    }
+
+    pub fn zone(mut self, zone: impl Into<String>) -> Self {
+        self.zone = Some(zone.into());
+        self
+    }
+
+    fn applies(&self, mode: &str, screen: Option<&str>, zone: Option<&str>) -> bool {
+        self.mode.as_deref().is_none_or(|wanted| wanted == mode)
+            && self
+                .screen
+                .as_deref()
+                .is_none_or(|wanted| Some(wanted) == screen)
+            && self
+                .zone
+                .as_deref()
+                .is_none_or(|wanted| Some(wanted) == zone)
+    }
+
+    fn specificity(&self) -> u8 {
+        (self.zone.is_some() as u8) << 2
+            | (self.screen.is_some() as u8) << 1
+            | self.mode.is_some() as u8
+    }
+}
+
+#[derive(Debug, Clone)]
+struct KeyBinding {
+    sequence: Vec<KeyChord>,
+    action: String,
+    scope: KeyScope,
+}
+
+/// Named action triggered through the [`Keymap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+    pub name: String,
+    /// The sequence that was typed, e.g. `ctrl-x ctrl-s`.
+    pub keys: String,
+    /// Zone that had focus when the sequence completed.
+    pub zone: Option<String>,
+}
+
+impl Action {
+    pub fn is(&self, name: &str) -> bool {
+        self.name == name
+    }
+}
+
+/// Result of feeding a key press to the [`Keymap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyMatch {
+    /// The press completed a bound sequence.
+    Action { name: String, keys: String },
+    /// The press continues a longer sequence; more keys are needed.
+    Pending,
+    /// Nothing is bound; the press should be handled as a plain key.
+    Unbound,
+    /// The press broke off a half-typed sequence. The abandoned `keys` should
+    /// be handled as plain key presses, followed by the press itself resolved
+    /// on its own as `then`.
+    Abandoned {
+        keys: Vec<KeyEvent>,
+        then: Box<KeyMatch>,
+    },
+}
+
+#[derive(Debug)]
+struct KeymapState {
+    bindings: Vec<KeyBinding>,
+    mode: String,
+    pending: Vec<KeyChord>,
+    pending_keys: Vec<KeyEvent>,
+    timeout: Duration,
+    idle: Duration,
+}
+
+/// Maps key sequences to named actions.
+///
+/// Put a keymap in shared state (see [`ensure_keymap`]) and the runtime
+/// resolves key presses through it before plugins see them: a completed
+/// sequence is dispatched as [`RuntimeEvent::Action`](super::RuntimeEvent::Action)
+/// and the keys of a sequence are consumed. A press that breaks off a
+/// sequence, or a pause longer than the [timeout](Self::set_timeout), hands
+/// the keys typed so far back as plain presses. A bound sequence fires as soon
+/// as it is typed, so it shadows longer sequences that start with it.
+#[derive(Debug)]
+pub struct Keymap {
+    inner: RwLock<KeymapState>,
+}
+
+#[derive(Deserialize)]
+struct KeymapFile {
+    #[serde(default)]
+    mode: Option<String>,
+    bindings: Vec<BindingSpec>,
+}
+
+#[derive(Deserialize)]
+struct BindingSpec {
+    keys: String,
+    action: String,
+    #[serde(flatten)]
+    scope: KeyScope,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(KeymapState {
+                bindings: Vec::new(),
+                mode: DEFAULT_MODE.to_string(),
+                pending: Vec::new(),
+                pending_keys: Vec::new(),
+                timeout: DEFAULT_SEQUENCE_TIMEOUT,
+                idle: Duration::ZERO,
+            }),
+        }
+    }
+
+    /// Parse a keymap file:
+    ///
+    /// ```json
+    /// { "mode": "normal",
+    ///   "bindings": [
+    ///     {"keys": "g g", "action": "scroll_top", "mode": "normal"},
+    ///     {"keys": "ctrl-x ctrl-s", "action": "save"},
+    ///     {"keys": "q", "action": "close", "zone": "app:help"} ] }
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self> {
+        let file: KeymapFile =
+            serde_json::from_str(json).map_err(|err| LayoutError::Keymap(err.to_string()))?;
+        let keymap = Self::new();
+        if let Some(mode) = file.mode {
+            keymap.set_mode(mode);
+        }
+        for binding in file.bindings {
+            keymap.bind_scoped(&binding.keys, binding.action, binding.scope)?;
+        }
+        Ok(keymap)
+    }
+
+    /// Read a keymap file from disk. Only JSON (`.json`) is supported.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            return Err(LayoutError::Keymap(format!(
+                "unsupported keymap file `{}` (expected .json)",
+                path.display()
+            )));
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Self::from_json(&contents).map_err(|err| match err {
+            LayoutError::Keymap(message) => {
+                LayoutError::Keymap(format!("{}: {message}", path.display()))
+            }
+            other => other,
+        })
+    }
+
+    /// Bind `keys` to `action` in every mode, screen and zone.
+    pub fn bind(&self, keys: &str, action: impl Into<String>) -> Result<()> {
+        self.bind_scoped(keys, action, KeyScope::default())
+    }
+
+    pub fn bind_scoped(
+        &self,
+        keys: &str,
+        action: impl Into<String>,
+        scope: KeyScope,
+    ) -> Result<()> {
+        let sequence = KeyChord::parse_sequence(keys)?;
+        self.write().bindings.push(KeyBinding {
+            sequence,
+            action: action.into(),
+            scope,
+        });
+        Ok(())
+    }
+
+    /// Drop every binding of `keys` in exactly `scope`.
+    pub fn unbind(&self, keys: &str, scope: &KeyScope) -> Result<()> {
+        let sequence = KeyChord::parse_sequence(keys)?;
+        self.write()
+            .bindings
+            .retain(|binding| binding.sequence != sequence || binding.scope != *scope);
+        Ok(())
+    }
+
+    pub fn mode(&self) -> String {
+        self.read().mode.clone()
+    }
+
+    /// Switch modes, abandoning any half-typed sequence.
+    pub fn set_mode(&self, mode: impl Into<String>) {
+        let mut state = self.write();
+        state.mode = mode.into();
+        state.pending.clear();
+        state.pending_keys.clear();
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.read().timeout
+    }
+
+    /// Give up on a half-typed sequence once no key arrived for `timeout`.
+    pub fn set_timeout(&self, timeout: Duration) {
+        self.write().timeout = timeout;
+    }
+
+    /// Time left before the half-typed sequence times out, if there is one.
+    pub fn time_left(&self) -> Option<Duration> {
+        let state = self.read();
+        (!state.pending.is_empty()).then(|| state.timeout.saturating_sub(state.idle))
+    }
+
+    /// Let `elapsed` pass without a key press. Once the half-typed sequence
+    /// has waited out the timeout it is dropped and its keys are returned to
+    /// be handled as plain presses.
+    pub fn expire(&self, elapsed: Duration) -> Vec<KeyEvent> {
+        let mut state = self.write();
+        if state.pending.is_empty() {
+            return Vec::new();
+        }
+        state.idle = state.idle.saturating_add(elapsed);
+        if state.idle < state.timeout {
+            return Vec::new();
+        }
+        state.pending.clear();
+        std::mem::take(&mut state.pending_keys)
+    }
+
+    /// Keys typed so far towards a longer sequence, e.g. to show `ctrl-x-`
+    /// in a status bar.
+    pub fn pending(&self) -> Vec<KeyChord> {
+        self.read().pending.clone()
+    }
+
+    /// Resolve a key press with `screen` active and `zone` focused.
+    pub fn feed(&self, key: &KeyEvent, screen: Option<&str>, zone: Option<&str>) -> KeyMatch {
+        if key.kind == KeyEventKind::Release {
+            return KeyMatch::Unbound;
+        }
+        let mut state = self.write();
+        let chord = KeyChord::from(key);
+        state.idle = Duration::ZERO;
+        state.pending.push(chord);
+        state.pending_keys.push(*key);
+        let mut result = state.lookup(screen, zone);
+        let mut abandoned = Vec::new();
+        if result == KeyMatch::Unbound && state.pending.len() > 1 {
+            abandoned = std::mem::replace(&mut state.pending_keys, vec![*key]);
+            abandoned.pop();
+            state.pending = vec![chord];
+            result = state.lookup(screen, zone);
+        }
+        if result != KeyMatch::Pending {
+            state.pending.clear();
+            state.pending_keys.clear();
+        }
+        if abandoned.is_empty() {
+            result
+        } else {
+            KeyMatch::Abandoned {
+                keys: abandoned,
+                then: Box::new(result),
+            }
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, KeymapState> {
+        self.inner.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, KeymapState> {
+        self.inner.write().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl KeymapState {
+    fn lookup(&self, screen: Option<&str>, zone: Option<&str>) -> KeyMatch {
+        let candidates = self.bindings.iter().filter(|binding| {
+            binding.sequence.starts_with(&self.pending)
+                && binding.scope.applies(&self.mode, screen, zone)
+        });
+        let mut longer = false;
+        let mut exact: Option<&KeyBinding> = None;
+        for binding in candidates {
+            if binding.sequence.len() > self.pending.len() {
+                longer = true;
+            } else if exact
+                .is_none_or(|best| best.scope.specificity() <= binding.scope.specificity())
+            {
+                exact = Some(binding);
+            }
+        }
+        match exact {
+            Some(binding) => KeyMatch::Action {
+                name: binding.action.clone(),
+                keys: self
+                    .pending
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            },
+            None if longer => KeyMatch::Pending,
+            None => KeyMatch::Unbound,
+        }
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub type SharedKeymap = Arc<Keymap>;
+
+pub fn ensure_keymap(
+    ctx: &RuntimeContext<'_>,
+) -> std::result::Result<SharedKeymap, SharedStateError> {
+    ctx.shared_init::<Keymap, _>(Keymap::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn ch(ch: char) -> KeyEvent {
+        press(KeyCode::Char(ch), KeyModifiers::NONE)
+    }
+
+    fn action(name: &str, keys: &str) -> KeyMatch {
+        KeyMatch::Action {
+            name: name.to_string(),
+            keys: keys.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_vim_and_emacs_notation() {
+        let ctrl_x: KeyChord = "C-x".parse().unwrap();
+        assert_eq!(
+            ctrl_x,
+            KeyChord::new(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        );
+        assert_eq!("ctrl+x".parse::<KeyChord>().unwrap(), ctrl_x);
+        assert_eq!(
+            "shift-g".parse::<KeyChord>().unwrap(),
+            "G".parse::<KeyChord>().unwrap()
+        );
+        assert_eq!(
+            "M-enter".parse::<KeyChord>().unwrap().to_string(),
+            "alt-enter"
+        );
+        assert_eq!("ctrl--".parse::<KeyChord>().unwrap().to_string(), "ctrl--");
+        assert_eq!("F12".parse::<KeyChord>().unwrap().code, KeyCode::F(12));
+        assert!("hyper-x".parse::<KeyChord>().is_err());
+        assert!(KeyChord::parse_sequence("  ").is_err());
+    }
+
+    #[test]
+    fn chords_resolve_within_mode_and_zone_scopes() {
+        let keymap = Keymap::from_json(
+            r#"{"bindings": [
+                {"keys": "g g", "action": "top", "mode": "normal"},
+                {"keys": "ctrl-x ctrl-s", "action": "save"},
+                {"keys": "q", "action": "quit"},
+                {"keys": "q", "action": "close_help", "zone": "help"}
+            ]}"#,
+        )
+        .unwrap();
+        let ctrl = |c| press(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        assert_eq!(keymap.feed(&ch('g'), None, None), KeyMatch::Pending);
+        assert_eq!(keymap.feed(&ch('g'), None, None), action("top", "g g"));
+        assert_eq!(keymap.feed(&ctrl('x'), None, None), KeyMatch::Pending);
+        assert_eq!(keymap.pending().len(), 1);
+        assert_eq!(
+            keymap.feed(&ctrl('s'), None, None),
+            action("save", "ctrl-x ctrl-s")
+        );
+
+        // Breaking off a chord hands its keys back and retries the key alone.
+        assert_eq!(keymap.feed(&ch('g'), None, None), KeyMatch::Pending);
+        assert_eq!(
+            keymap.feed(&ch('q'), None, None),
+            KeyMatch::Abandoned {
+                keys: vec![ch('g')],
+                then: Box::new(action("quit", "q")),
+            }
+        );
+        assert_eq!(
+            keymap.feed(&ch('q'), None, Some("help")),
+            action("close_help", "q")
+        );
+
+        keymap.set_mode("insert");
+        assert_eq!(keymap.feed(&ch('g'), None, None), KeyMatch::Unbound);
+        assert!(keymap.pending().is_empty());
+    }
+
+    #[test]
+    fn half_typed_sequences_time_out() {
+        let keymap = Keymap::new();
+        keymap.bind("g g", "top").unwrap();
+        keymap.set_timeout(Duration::from_millis(500));
+        assert_eq!(keymap.time_left(), None);
+
+        assert_eq!(keymap.feed(&ch('g'), None, None), KeyMatch::Pending);
+        assert!(keymap.expire(Duration::from_millis(300)).is_empty());
+        assert_eq!(keymap.time_left(), Some(Duration::from_millis(200)));
+        assert_eq!(keymap.expire(Duration::from_millis(200)), vec![ch('g')]);
+        assert!(keymap.pending().is_empty());
+
+        // Keys typed within the timeout still complete the sequence.
+        assert_eq!(keymap.feed(&ch('g'), None, None), KeyMatch::Pending);
+        assert!(keymap.expire(Duration::from_millis(400)).is_empty());
+        assert_eq!(keymap.feed(&ch('g'), None, None), action("top", "g g"));
+    }
+}
@@ -1,30 +1,47 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::io::Write;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use boxy::api::layout::BoxBuilder;
 use boxy::visual::{BoxStyle, NORMAL};
-use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
+use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, MouseEvent};
 use serde_json::json;
 
 use self::audit::{NullRuntimeAudit, RuntimeAudit, RuntimeAuditEventBuilder, RuntimeAuditStage};
+use self::external::{CustomEvent, EventSender, ExternalEvents, Input};
 use self::focus::{FocusController, FocusEntry, ensure_focus_registry};
+use self::keymap::{Action, KeyMatch, Keymap};
+use self::mouse::{MouseTracker, ZoneMouse, ZoneMouseKind};
 use self::screens::{ScreenActivation, ScreenManager};
+use self::timers::{MIN_TIMER_PERIOD, TimerHandle, TimerQueue, TimerRequest};
 use crate::logging::{event_with_fields, json_kv};
+use crate::registry::ZoneState;
+use crate::style::{HorizontalAlign, Overflow, VerticalAlign};
 use crate::{
-    AnsiRenderer, Layout, LayoutError, LogLevel, Logger, Rect, Result, RuntimeMetrics, Size,
-    ZoneRegistry,
+    AnsiRenderer, Layout, LayoutEdit, LayoutError, LogLevel, LogScroll, Logger, Rect, Result,
+    RuntimeMetrics, Size, StyledText, ZoneFormat, ZoneRegistry,
 };
 pub mod audit;
 pub mod bundles;
 pub mod diagnostics;
 pub mod driver;
+pub mod external;
 pub mod focus;
+pub mod hot_reload;
+pub mod inspector;
+pub mod keymap;
+pub mod mouse;
 pub mod screens;
 pub mod shared_state;
+pub mod splitter;
+pub mod timers;
 
 const RUNTIME_FOCUS_OWNER: &str = "room::runtime";
+const KEYMAP_CONSUMER: &str = "room::keymap";
+/// Most published events delivered for one top-level event, so plugins that
+/// keep publishing to each other cannot hang the runtime.
+const MAX_PUBLISHED_PER_EVENT: usize = 1024;
 
 pub struct PluginBundle {
     entries: Vec<PluginEntry>,
@@ -111,6 +128,13 @@ pub struct RuntimeConfig {
     pub loop_iteration_limit: Option<usize>,
     /// When present, bypasses the driver loop and uses a bounded simulated loop.
     pub simulated_loop: Option<SimulatedLoop>,
+    /// Log `Layout::diagnose` warnings through the logger after every resize.
+    pub log_layout_warnings: bool,
+    /// Smallest terminal the app supports. Below it plugins get no render
+    /// hooks and a "terminal too small" notice replaces the frame.
+    pub min_size: Option<Size>,
+    /// Focus a zone when it is clicked, as reported through `ZoneMouse`.
+    pub focus_on_click: bool,
 }
 
 impl Default for RuntimeConfig {
@@ -125,6 +149,9 @@ impl Default for RuntimeConfig {
             default_focus_zone: None,
             loop_iteration_limit: None,
             simulated_loop: None,
+            log_layout_warnings: false,
+            min_size: None,
+            focus_on_click: false,
         }
     }
 }
@@ -171,8 +198,18 @@ pub enum RuntimeEvent {
     CursorHidden(Cursor),
     FocusChanged(FocusChange),
     Tick { elapsed: Duration },
+    /// A timer scheduled through `RuntimeContext::schedule_after` or
+    /// `schedule_every` came due. Every plugin sees it; match on `token`.
+    Timer { handle: TimerHandle, token: String },
     Key(KeyEvent),
     Mouse(MouseEvent),
+    /// A mouse event no plugin consumed, resolved to the zone under the pointer.
+    ZoneMouse(ZoneMouse),
+    /// A key sequence bound in the shared [`Keymap`](keymap::Keymap).
+    Action(Action),
+    /// Sent from another thread through [`RoomRuntime::event_sender`] or
+    /// published by a plugin through `RuntimeContext::publish`.
+    Custom(CustomEvent),
     Paste(String),
     FocusGained,
     FocusLost,
@@ -180,9 +217,21 @@ pub enum RuntimeEvent {
     Raw(CrosstermEvent),
 }
 
+impl RuntimeEvent {
+    /// The payload of a custom event carrying a `T`, which is how plugins
+    /// subscribe to events published with `RuntimeContext::publish`.
+    pub fn custom<T: 'static>(&self) -> Option<&T> {
+        match self {
+            RuntimeEvent::Custom(custom) => custom.payload(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LoopEventKind {
     Tick,
+    Timer,
     Key,
     Mouse,
     Paste,
@@ -196,6 +245,7 @@ impl LoopEventKind {
     pub fn as_str(&self) -> &'static str {
         match self {
             LoopEventKind::Tick => "tick",
+            LoopEventKind::Timer => "timer",
             LoopEventKind::Key => "key",
             LoopEventKind::Mouse => "mouse",
             LoopEventKind::Paste => "paste",
@@ -209,6 +259,7 @@ impl LoopEventKind {
     pub fn from_runtime_event(event: &RuntimeEvent) -> Option<Self> {
         match event {
             RuntimeEvent::Tick { .. } => Some(LoopEventKind::Tick),
+            RuntimeEvent::Timer { .. } => Some(LoopEventKind::Timer),
             RuntimeEvent::Key(_) => Some(LoopEventKind::Key),
             RuntimeEvent::Mouse(_) => Some(LoopEventKind::Mouse),
             RuntimeEvent::Paste(_) => Some(LoopEventKind::Paste),
@@ -274,6 +325,25 @@ pub struct FocusChange {
     pub to: Option<FocusTarget>,
 }
 
+/// A layout zone whose rect changed after a resize or layout swap. Its content
+/// is kept and re-wrapped automatically; plugins only need this to re-layout
+/// content that depends on the zone size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneResized {
+    pub zone: String,
+    pub old: Rect,
+    pub new: Rect,
+}
+
+/// A layout zone that stopped or started being placed, e.g. when a responsive
+/// layout crosses a breakpoint. Hidden zones keep their content and accept
+/// updates; they are painted again once shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZoneVisibility {
+    Hidden { zone: String },
+    Shown { zone: String, rect: Rect },
+}
+
 /// Control the propagation of an event across plugins.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventFlow {
@@ -335,8 +405,17 @@ impl Default for BoxConfig {
 /// Context passed to plugins so they can interact with the runtime safely.
 pub struct RuntimeContext<'a> {
     rects: &'a HashMap<String, Rect>,
+    constraints: &'a HashMap<String, String>,
     shared_state: &'a shared_state::SharedState,
     zone_updates: Vec<ZoneUpdate>,
+    format_updates: Vec<(String, ZoneFormat)>,
+    overlay_updates: Vec<OverlayUpdate>,
+    log_updates: Vec<LogUpdate>,
+    intrinsic_updates: Vec<(String, Size)>,
+    layout_update: Option<Box<dyn Layout>>,
+    layout_edits: Vec<LayoutEdit>,
+    timer_requests: Vec<TimerRequest>,
+    published: Vec<CustomEvent>,
     redraw_requested: bool,
     exit_requested: bool,
     cursor_hint: Option<(u16, u16)>,
@@ -345,11 +424,24 @@ pub struct RuntimeContext<'a> {
 }
 
 impl<'a> RuntimeContext<'a> {
-    fn new(rects: &'a HashMap<String, Rect>, shared_state: &'a shared_state::SharedState) -> Self {
+    fn new(
+        rects: &'a HashMap<String, Rect>,
+        constraints: &'a HashMap<String, String>,
+        shared_state: &'a shared_state::SharedState,
+    ) -> Self {
         Self {
             rects,
+            constraints,
             shared_state,
             zone_updates: Vec::new(),
+            format_updates: Vec::new(),
+            overlay_updates: Vec::new(),
+            log_updates: Vec::new(),
+            intrinsic_updates: Vec::new(),
+            layout_update: None,
+            layout_edits: Vec::new(),
+            timer_requests: Vec::new(),
+            published: Vec::new(),
             redraw_requested: false,
             exit_requested: false,
             cursor_hint: None,
@@ -362,8 +454,7 @@ impl<'a> RuntimeContext<'a> {
     pub fn set_zone(&mut self, zone_id: impl Into<String>, content: impl Into<String>) {
         self.zone_updates.push(ZoneUpdate {
             zone: zone_id.into(),
-            content: content.into(),
-            pre_rendered: false,
+            payload: ZonePayload::Text(content.into()),
         });
         self.redraw_requested = true;
     }
@@ -376,12 +467,116 @@ impl<'a> RuntimeContext<'a> {
     ) {
         self.zone_updates.push(ZoneUpdate {
             zone: zone_id.into(),
-            content: content.into(),
-            pre_rendered: true,
+            payload: ZonePayload::PreRendered(content.into()),
+        });
+        self.redraw_requested = true;
+    }
+
+    /// Queue styled content for a zone. Spans are wrapped and padded to the
+    /// zone like plain text while keeping their styles.
+    pub fn set_zone_styled(&mut self, zone_id: impl Into<String>, content: impl Into<StyledText>) {
+        self.zone_updates.push(ZoneUpdate {
+            zone: zone_id.into(),
+            payload: ZonePayload::Styled(content.into()),
+        });
+        self.redraw_requested = true;
+    }
+
+    /// Append a line to a log zone. The zone keeps a bounded history (see
+    /// [`RuntimeContext::set_zone_log_capacity`]) and follows the tail unless
+    /// scrolled back; `set_zone` turns it back into a plain zone.
+    pub fn append_zone(&mut self, zone_id: impl Into<String>, line: impl Into<String>) {
+        self.zone_updates.push(ZoneUpdate {
+            zone: zone_id.into(),
+            payload: ZonePayload::Append(line.into()),
+        });
+        self.redraw_requested = true;
+    }
+
+    /// Limit how many lines a log zone retains.
+    pub fn set_zone_log_capacity(&mut self, zone_id: impl Into<String>, capacity: usize) {
+        self.log_updates.push(LogUpdate::Capacity {
+            zone: zone_id.into(),
+            capacity,
+        });
+        self.redraw_requested = true;
+    }
+
+    /// Scroll a log zone's viewport. `LogScroll::Bottom` resumes following.
+    pub fn scroll_zone(&mut self, zone_id: impl Into<String>, scroll: LogScroll) {
+        self.log_updates.push(LogUpdate::Scroll {
+            zone: zone_id.into(),
+            scroll,
+        });
+        self.redraw_requested = true;
+    }
+
+    /// Set how a zone wraps, truncates, and aligns its text.
+    pub fn set_zone_format(&mut self, zone_id: impl Into<String>, format: ZoneFormat) {
+        self.format_updates.push((zone_id.into(), format));
+        self.redraw_requested = true;
+    }
+
+    /// Open (or move) an overlay zone drawn above the layout at `rect`. Higher
+    /// `z_index` values stack on top; layout zones sit at 0. Overlays are
+    /// applied before zone content queued in the same callback, so the overlay
+    /// can be filled with `set_zone` right away.
+    pub fn open_overlay(&mut self, zone_id: impl Into<String>, rect: Rect, z_index: i32) {
+        self.overlay_updates.push(OverlayUpdate::Open {
+            zone: zone_id.into(),
+            rect,
+            z_index,
         });
         self.redraw_requested = true;
     }
 
+    /// Close an overlay; the zones it covered are repainted.
+    pub fn close_overlay(&mut self, zone_id: impl Into<String>) {
+        self.overlay_updates
+            .push(OverlayUpdate::Close(zone_id.into()));
+        self.redraw_requested = true;
+    }
+
+    /// Deliver `RuntimeEvent::Timer` with `token` once, `delay` from now.
+    pub fn schedule_after(&mut self, delay: Duration, token: impl Into<String>) -> TimerHandle {
+        self.schedule(delay, None, token.into())
+    }
+
+    /// Deliver `RuntimeEvent::Timer` with `token` every `period` until the
+    /// timer is cancelled. A runtime that falls behind skips missed periods
+    /// rather than firing them in a burst.
+    pub fn schedule_every(&mut self, period: Duration, token: impl Into<String>) -> TimerHandle {
+        let period = period.max(MIN_TIMER_PERIOD);
+        self.schedule(period, Some(period), token.into())
+    }
+
+    /// Stop a timer; cancelling one that already fired is a no-op.
+    pub fn cancel_timer(&mut self, handle: TimerHandle) {
+        self.timer_requests.push(TimerRequest::Cancel(handle));
+    }
+
+    fn schedule(&mut self, delay: Duration, every: Option<Duration>, token: String) -> TimerHandle {
+        let handle = TimerHandle::next();
+        self.timer_requests.push(TimerRequest::Schedule {
+            handle,
+            token,
+            delay,
+            every,
+        });
+        handle
+    }
+
+    /// Deliver `event` to every plugin as `RuntimeEvent::Custom` once the
+    /// current event has been handled, in priority order and stopping at the
+    /// first plugin that consumes it. Subscribers match on
+    /// [`RuntimeEvent::custom`].
+    pub fn publish<T>(&mut self, event: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.published.push(CustomEvent::typed(event));
+    }
+
     /// Request that the renderer runs even if no zones changed.
     pub fn request_render(&mut self) {
         self.redraw_requested = true;
@@ -445,11 +640,48 @@ impl<'a> RuntimeContext<'a> {
         self.reported_error = Some(error);
     }
 
+    /// Report the natural size of a zone's content. Grid tracks sized with
+    /// `GridSize::Auto`, `Min` or `Max` follow it; the layout is re-solved
+    /// whenever a reported size changes.
+    pub fn set_intrinsic_size(&mut self, zone_id: impl Into<String>, size: Size) {
+        self.intrinsic_updates.push((zone_id.into(), size));
+        self.redraw_requested = true;
+    }
+
+    /// Swap the active layout after this plugin returns. Zones the new layout
+    /// keeps retain their content; zones it drops are hidden. A layout that
+    /// fails to solve is reported as a recoverable `layout` error and the
+    /// current one stays active.
+    pub fn set_layout(&mut self, layout: impl Layout + 'static) {
+        self.layout_update = Some(Box::new(layout));
+        self.redraw_requested = true;
+    }
+
+    /// Edit the active layout in place, e.g. to open or close a pane. Edits
+    /// run in order after this plugin returns (after any `set_layout`) and the
+    /// layout is re-solved once; an edit the layout rejects is reported as a
+    /// recoverable `layout` error and skipped.
+    pub fn edit_layout(&mut self, edit: LayoutEdit) {
+        self.layout_edits.push(edit);
+        self.redraw_requested = true;
+    }
+
     /// Fetch the solved rectangle for a zone if available.
     pub fn rect(&self, zone_id: &str) -> Option<&Rect> {
         self.rects.get(zone_id)
     }
 
+    /// Every solved rect, including overlays and zones collapsed to zero size.
+    pub fn rects(&self) -> &HashMap<String, Rect> {
+        self.rects
+    }
+
+    /// Sizing rule the layout applied to a zone, e.g. `Fixed(20)`; see
+    /// [`Layout::describe`].
+    pub fn zone_constraint(&self, zone_id: &str) -> Option<&str> {
+        self.constraints.get(zone_id).map(String::as_str)
+    }
+
     pub fn render_zone_with_box(
         &self,
         zone_id: &str,
@@ -510,6 +742,14 @@ impl<'a> RuntimeContext<'a> {
     fn into_outcome(self) -> ContextOutcome {
         ContextOutcome {
             zone_updates: self.zone_updates,
+            format_updates: self.format_updates,
+            overlay_updates: self.overlay_updates,
+            log_updates: self.log_updates,
+            intrinsic_updates: self.intrinsic_updates,
+            layout_update: self.layout_update,
+            layout_edits: self.layout_edits,
+            timer_requests: self.timer_requests,
+            published: self.published,
             redraw_requested: self.redraw_requested,
             exit_requested: self.exit_requested,
             cursor_hint: self.cursor_hint,
@@ -521,6 +761,14 @@ impl<'a> RuntimeContext<'a> {
 
 struct ContextOutcome {
     zone_updates: Vec<ZoneUpdate>,
+    format_updates: Vec<(String, ZoneFormat)>,
+    overlay_updates: Vec<OverlayUpdate>,
+    log_updates: Vec<LogUpdate>,
+    intrinsic_updates: Vec<(String, Size)>,
+    layout_update: Option<Box<dyn Layout>>,
+    layout_edits: Vec<LayoutEdit>,
+    timer_requests: Vec<TimerRequest>,
+    published: Vec<CustomEvent>,
     redraw_requested: bool,
     exit_requested: bool,
     cursor_hint: Option<(u16, u16)>,
@@ -530,8 +778,28 @@ struct ContextOutcome {
 
 struct ZoneUpdate {
     zone: String,
-    content: String,
-    pre_rendered: bool,
+    payload: ZonePayload,
+}
+
+enum OverlayUpdate {
+    Open {
+        zone: String,
+        rect: Rect,
+        z_index: i32,
+    },
+    Close(String),
+}
+
+enum ZonePayload {
+    Text(String),
+    PreRendered(String),
+    Styled(StyledText),
+    Append(String),
+}
+
+enum LogUpdate {
+    Capacity { zone: String, capacity: usize },
+    Scroll { zone: String, scroll: LogScroll },
 }
 
 #[derive(Default)]
@@ -667,6 +935,22 @@ pub trait RoomPlugin: Send {
         Ok(())
     }
 
+    fn on_zone_resized(
+        &mut self,
+        _ctx: &mut RuntimeContext<'_>,
+        _resized: &ZoneResized,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_zone_visibility(
+        &mut self,
+        _ctx: &mut RuntimeContext<'_>,
+        _change: &ZoneVisibility,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn init(&mut self, _ctx: &mut RuntimeContext<'_>) -> Result<()> {
         Ok(())
     }
@@ -709,8 +993,17 @@ pub struct RoomRuntime {
     cursor_manager: CursorManager,
     pending_cursor_events: Vec<CursorEvent>,
     pending_focus_changes: Vec<FocusChange>,
+    pending_zone_resizes: Vec<ZoneResized>,
+    pending_visibility: Vec<ZoneVisibility>,
+    intrinsic_sizes: HashMap<String, Size>,
+    zone_constraints: HashMap<String, String>,
     pending_errors: Vec<RuntimeError>,
     last_focus_entry: Option<FocusEntry>,
+    mouse: MouseTracker,
+    timers: TimerQueue,
+    external: Option<ExternalEvents>,
+    pending_published: VecDeque<CustomEvent>,
+    dispatch_depth: usize,
     fatal_active: bool,
 }
 
@@ -721,13 +1014,15 @@ impl RoomRuntime {
 
     pub fn with_config(
         layout: impl Layout + 'static,
-        renderer: AnsiRenderer,
+        mut renderer: AnsiRenderer,
         initial_size: Size,
         config: RuntimeConfig,
     ) -> Result<Self> {
+        renderer.resize(initial_size);
         let mut registry = ZoneRegistry::new();
         let rects = layout.solve(initial_size)?;
         registry.sync_layout(&rects);
+        let zone_constraints = layout.describe(initial_size);
 
         let audit = config
             .audit
@@ -756,8 +1051,17 @@ impl RoomRuntime {
             cursor_manager: CursorManager::new(),
             pending_cursor_events: Vec::new(),
             pending_focus_changes: Vec::new(),
+            pending_zone_resizes: Vec::new(),
+            pending_visibility: Vec::new(),
+            intrinsic_sizes: HashMap::new(),
+            zone_constraints,
             pending_errors: Vec::new(),
             last_focus_entry: None,
+            mouse: MouseTracker::default(),
+            timers: TimerQueue::default(),
+            external: None,
+            pending_published: VecDeque::new(),
+            dispatch_depth: 0,
             fatal_active: false,
         };
         runtime.audit_record(RuntimeAuditStage::RuntimeConstructed, []);
@@ -875,6 +1179,7 @@ impl RoomRuntime {
 
         self.bootstrap(stdout)?;
         let mut last_tick = Instant::now();
+        let mut last_timer_check = last_tick;
         let mut loop_iterations = 0;
 
         while !self.should_exit {
@@ -891,14 +1196,32 @@ impl RoomRuntime {
             }
             loop_iterations += 1;
 
-            let timeout = self
+            let mut timeout = self
                 .config
                 .tick_interval
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or_else(|| Duration::from_millis(0));
+            let next_due = self.timers.next_due().into_iter().chain(
+                self.shared_state
+                    .get::<Keymap>()
+                    .ok()
+                    .and_then(|keymap| keymap.time_left()),
+            );
+            if let Some(next_due) = next_due.min() {
+                let next_due = next_due.saturating_sub(last_timer_check.elapsed());
+                timeout = timeout.min(next_due);
+            }
+
+            if self.poll_input(timeout)? {
+                // Catch the clocks up first so a key sequence waits its full
+                // timeout from this press.
+                let now = Instant::now();
+                if self.advance_timers(now.duration_since(last_timer_check))? {
+                    self.render_if_needed(stdout)?;
+                }
+                last_timer_check = now;
 
-            if event::poll(timeout)? {
-                let crossterm_event = event::read()?;
+                let crossterm_event = self.read_input()?;
                 let runtime_event = self.map_event(crossterm_event)?;
 
                 // Check if this is a resize before dispatching (to coalesce rapid resizes)
@@ -909,7 +1232,7 @@ impl RoomRuntime {
                 // If this was a resize and more events are immediately ready, skip render
                 // to coalesce rapid resize events (prevents race condition artifacts)
                 let should_skip_render =
-                    is_resize && event::poll(Duration::from_millis(0)).unwrap_or(false);
+                    is_resize && self.poll_input(Duration::ZERO).unwrap_or(false);
 
                 if !should_skip_render {
                     self.render_if_needed(stdout)?;
@@ -929,6 +1252,13 @@ impl RoomRuntime {
                 self.render_if_needed(stdout)?;
             }
 
+            let now = Instant::now();
+            let fired = self.advance_timers(now.duration_since(last_timer_check))?;
+            last_timer_check = now;
+            if self.dispatch_external()? || fired {
+                self.render_if_needed(stdout)?;
+            }
+
             self.maybe_emit_metrics();
         }
 
@@ -955,6 +1285,7 @@ impl RoomRuntime {
                 last_tick = now;
                 self.dispatch_event(RuntimeEvent::Tick { elapsed })?;
                 self.audit_record(RuntimeAuditStage::TickDispatched, []);
+                self.advance_timers(elapsed)?;
             }
 
             // Apply pending renders
@@ -1013,7 +1344,16 @@ impl RoomRuntime {
                 }
                 other => other,
             };
+            // Scripted runs have no wall clock; their ticks drive the timers.
+            let elapsed = match event {
+                RuntimeEvent::Tick { elapsed } => Some(elapsed),
+                _ => None,
+            };
             self.dispatch_event(event)?;
+            if let Some(elapsed) = elapsed {
+                self.advance_timers(elapsed)?;
+            }
+            self.dispatch_external()?;
             self.render_if_needed(stdout)?;
             if self.should_exit {
                 break;
@@ -1035,6 +1375,61 @@ impl RoomRuntime {
     }
 
     fn dispatch_event(&mut self, event: RuntimeEvent) -> Result<()> {
+        self.dispatch_event_with(event, true)
+    }
+
+    /// Dispatch `event`, resolving key presses through the keymap only when
+    /// `resolve_keys` is set; keys the keymap handed back are replayed without.
+    /// Events published while handling a top-level event are delivered once
+    /// it is done.
+    fn dispatch_event_with(&mut self, event: RuntimeEvent, resolve_keys: bool) -> Result<()> {
+        if self.dispatch_depth > 0 {
+            return self.dispatch_nested(event, resolve_keys);
+        }
+        self.dispatch_nested(event, resolve_keys)?;
+        self.dispatch_published()
+    }
+
+    fn dispatch_nested(&mut self, event: RuntimeEvent, resolve_keys: bool) -> Result<()> {
+        self.dispatch_depth += 1;
+        let result = self.dispatch_one(event, resolve_keys);
+        self.dispatch_depth -= 1;
+        result
+    }
+
+    /// Deliver published events oldest first, including those published while
+    /// delivering them, up to [`MAX_PUBLISHED_PER_EVENT`]. Events beyond that
+    /// are dropped and reported as a recoverable `publish` error.
+    fn dispatch_published(&mut self) -> Result<()> {
+        let mut delivered = 0;
+        while let Some(event) = self.pending_published.pop_front() {
+            if delivered == MAX_PUBLISHED_PER_EVENT {
+                let dropped = self.pending_published.len() + 1;
+                self.pending_published.clear();
+                self.pending_errors.push(RuntimeError {
+                    category: "publish".to_string(),
+                    source: None,
+                    message: format!(
+                        "dropped {dropped} published events after delivering \
+                         {MAX_PUBLISHED_PER_EVENT}; `{}` was next",
+                        event.name
+                    ),
+                    recoverable: true,
+                });
+                return self.process_pending_errors();
+            }
+            delivered += 1;
+            self.log_runtime_event(
+                LogLevel::Debug,
+                "event_published",
+                [json_kv("name", json!(event.name.clone()))],
+            );
+            self.dispatch_nested(RuntimeEvent::Custom(event), true)?;
+        }
+        Ok(())
+    }
+
+    fn dispatch_one(&mut self, event: RuntimeEvent, resolve_keys: bool) -> Result<()> {
         let mut consumed = false;
         let mut consumed_by: Option<String> = None;
         let loop_kind = LoopEventKind::from_runtime_event(&event);
@@ -1051,6 +1446,40 @@ impl RoomRuntime {
             );
         }
 
+        let mut mapped = match &event {
+            RuntimeEvent::Key(key) if resolve_keys => self.resolve_keymap(key),
+            _ => KeyMatch::Unbound,
+        };
+        if let KeyMatch::Abandoned { keys, then } = mapped {
+            self.replay_keys(keys)?;
+            mapped = *then;
+        }
+        if mapped != KeyMatch::Unbound {
+            self.record_event_metric();
+            self.log_runtime_event(
+                LogLevel::Debug,
+                "event_dispatched",
+                [
+                    json_kv("event", json!(Self::describe_event(&event))),
+                    json_kv("consumed", json!(true)),
+                    json_kv("consumed_by", json!(KEYMAP_CONSUMER)),
+                ],
+            );
+            let mut builder = RuntimeAuditEventBuilder::new(RuntimeAuditStage::EventDispatched);
+            builder.detail("event", json!(Self::describe_event(&event)));
+            builder.detail("consumed", json!(true));
+            builder.detail("consumed_by", json!(KEYMAP_CONSUMER));
+            self.audit_record_event(builder.finish());
+            if let KeyMatch::Action { name, keys } = mapped {
+                let zone = self
+                    .last_focus_entry
+                    .as_ref()
+                    .map(|entry| entry.zone_id.clone());
+                return self.dispatch_event(RuntimeEvent::Action(Action { name, keys, zone }));
+            }
+            return Ok(());
+        }
+
         if self.screen_manager.is_some() {
             let mut manager = self
                 .screen_manager
@@ -1058,7 +1487,8 @@ impl RoomRuntime {
                 .expect("screen manager missing after presence check");
 
             let result: Result<(EventFlow, Vec<ScreenActivation>)> = (|| {
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 let flow = manager.handle_event(&mut ctx, &event)?;
                 let outcome = ctx.into_outcome();
                 self.apply_outcome(outcome)?;
@@ -1113,7 +1543,8 @@ impl RoomRuntime {
         for idx in 0..self.plugins.len() {
             let (flow, outcome, plugin_name) = {
                 let entry = &mut self.plugins[idx];
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 let flow = entry.plugin.on_event(&mut ctx, &event)?;
                 let name = entry.name.clone();
                 (flow, ctx.into_outcome(), name)
@@ -1125,6 +1556,10 @@ impl RoomRuntime {
                 break;
             }
         }
+        if !consumed && self.scroll_focused_log(&event)? {
+            consumed = true;
+            consumed_by = Some("room::runtime.log_scroll".to_string());
+        }
         self.record_event_metric();
         self.log_runtime_event(
             LogLevel::Debug,
@@ -1143,6 +1578,9 @@ impl RoomRuntime {
         );
         let mut builder = RuntimeAuditEventBuilder::new(RuntimeAuditStage::EventDispatched);
         builder.detail("event", json!(Self::describe_event(&event)));
+        if let RuntimeEvent::Custom(custom) = &event {
+            builder.detail("name", json!(custom.name.clone()));
+        }
         builder.detail("consumed", json!(consumed));
         if let Some(name) = consumed_by {
             builder.detail("consumed_by", json!(name));
@@ -1168,9 +1606,147 @@ impl RoomRuntime {
         }
         self.flush_notifications()?;
         self.process_pending_errors()?;
+        if !consumed
+            && let RuntimeEvent::Mouse(mouse) = &event
+            && let Some(zone_mouse) = self.mouse.resolve(&self.registry, mouse)
+        {
+            self.dispatch_zone_mouse(zone_mouse)?;
+        }
+        Ok(())
+    }
+
+    /// Handle for injecting [`CustomEvent`]s from other threads. Events are
+    /// dispatched as `RuntimeEvent::Custom` in the order they were sent: by
+    /// `run` as soon as they arrive, even while it waits for input, and by
+    /// `run_scripted` after each scripted event. Once `run` waits with a sender
+    /// handed out, terminal input is read on a background thread for the rest
+    /// of the runtime's life.
+    pub fn event_sender(&mut self) -> EventSender {
+        self.external
+            .get_or_insert_with(ExternalEvents::new)
+            .sender()
+    }
+
+    /// Wait up to `timeout` for terminal input, returning early without input
+    /// once external events are waiting. With an event sender handed out,
+    /// terminal input is read on a background thread so that a single wait
+    /// wakes up for either.
+    fn poll_input(&mut self, timeout: Duration) -> Result<bool> {
+        let Some(external) = self.external.as_mut() else {
+            return Ok(event::poll(timeout)?);
+        };
+        external.spawn_reader(|| event::read().map(Input::Terminal));
+        Ok(external.wait(Some(timeout), true))
+    }
+
+    /// Take the terminal event `poll_input` reported.
+    fn read_input(&mut self) -> Result<CrosstermEvent> {
+        match self.external.as_mut().and_then(ExternalEvents::next_input) {
+            Some(Ok(Input::Terminal(event))) => Ok(event),
+            Some(Ok(_)) => Err(LayoutError::Backend(
+                "terminal input reader stopped".to_string(),
+            )),
+            Some(Err(err)) => Err(err.into()),
+            None => Ok(event::read()?),
+        }
+    }
+
+    /// Dispatch the external events received so far. Returns whether there
+    /// were any.
+    fn dispatch_external(&mut self) -> Result<bool> {
+        let events = match self.external.as_mut() {
+            Some(external) => external.drain(),
+            None => return Ok(false),
+        };
+        let any = !events.is_empty();
+        for event in events {
+            self.dispatch_event(RuntimeEvent::Custom(event))?;
+        }
+        Ok(any)
+    }
+
+    /// Read input with `read` on a background thread, for drivers that take
+    /// it with [`wait_input`](Self::wait_input) instead of polling.
+    pub(crate) fn spawn_input_reader<F>(&mut self, read: F)
+    where
+        F: FnMut() -> std::io::Result<Input> + Send + 'static,
+    {
+        self.external
+            .get_or_insert_with(ExternalEvents::new)
+            .spawn_reader(read);
+    }
+
+    /// Block until the input reader delivers something or, once the runtime
+    /// has bootstrapped, external events are waiting; `None` means the latter,
+    /// see [`pump_external`](Self::pump_external).
+    pub(crate) fn wait_input(&mut self) -> Option<std::io::Result<Input>> {
+        let bootstrapped = self.start_instant.is_some();
+        let external = self.external.get_or_insert_with(ExternalEvents::new);
+        external.wait(None, bootstrapped);
+        external.next_input()
+    }
+
+    /// Dispatch waiting external events and render, for drivers that block
+    /// on their own input. Does nothing before the runtime has bootstrapped.
+    pub(crate) fn pump_external(&mut self, stdout: &mut impl Write) -> Result<bool> {
+        if self.start_instant.is_none() || !self.dispatch_external()? {
+            return Ok(false);
+        }
+        self.render_if_needed(stdout)?;
+        Ok(true)
+    }
+
+    /// Advance the timer clock and dispatch the timers that came due, along
+    /// with the keys of a key sequence that timed out. Returns whether any
+    /// fired.
+    fn advance_timers(&mut self, elapsed: Duration) -> Result<bool> {
+        let fired = self.timers.advance(elapsed);
+        let expired = match self.shared_state.get::<Keymap>() {
+            Ok(keymap) => keymap.expire(elapsed),
+            Err(_) => Vec::new(),
+        };
+        let any = !fired.is_empty() || !expired.is_empty();
+        for (handle, token) in fired {
+            self.dispatch_event(RuntimeEvent::Timer { handle, token })?;
+        }
+        self.replay_keys(expired)?;
+        Ok(any)
+    }
+
+    /// Dispatch keys the keymap gave up on as plain key presses.
+    fn replay_keys(&mut self, keys: Vec<KeyEvent>) -> Result<()> {
+        for key in keys {
+            self.dispatch_event_with(RuntimeEvent::Key(key), false)?;
+        }
         Ok(())
     }
 
+    fn resolve_keymap(&self, key: &KeyEvent) -> KeyMatch {
+        let Ok(keymap) = self.shared_state.get::<Keymap>() else {
+            return KeyMatch::Unbound;
+        };
+        let screen = self
+            .screen_manager
+            .as_ref()
+            .and_then(|manager| manager.active_id());
+        let zone = self
+            .last_focus_entry
+            .as_ref()
+            .map(|entry| entry.zone_id.as_str());
+        keymap.feed(key, screen, zone)
+    }
+
+    fn dispatch_zone_mouse(&mut self, zone_mouse: ZoneMouse) -> Result<()> {
+        let clicked = matches!(
+            zone_mouse.kind,
+            ZoneMouseKind::Click(_) | ZoneMouseKind::DoubleClick(_)
+        );
+        if clicked && self.config.focus_on_click {
+            self.focus_zone(zone_mouse.zone_id.clone())?;
+        }
+        self.dispatch_event(RuntimeEvent::ZoneMouse(zone_mouse))
+    }
+
     fn render_if_needed(&mut self, stdout: &mut impl Write) -> Result<()> {
         if !self.redraw_requested {
             self.audit_record(RuntimeAuditStage::RenderSkipped, []);
@@ -1179,22 +1755,34 @@ impl RoomRuntime {
 
         self.redraw_requested = false;
 
+        // Zones stay dirty while the notice is up; growing back past the
+        // minimum resizes, which repaints everything.
+        if let Some(min) = self.undersized() {
+            self.render_size_notice(stdout, min)?;
+            self.flush_notifications()?;
+            self.process_pending_errors()?;
+            return Ok(());
+        }
+
         for idx in 0..self.plugins.len() {
             let outcome = {
                 let entry = &mut self.plugins[idx];
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 entry.plugin.before_render(&mut ctx)?;
                 ctx.into_outcome()
             };
             self.apply_outcome(outcome)?;
         }
 
+        let exposed = self.registry.take_exposed();
         let dirty = self.registry.take_dirty();
-        if !dirty.is_empty() {
+        if !dirty.is_empty() || !exposed.is_empty() {
             if self.resize_pending {
-                write!(stdout, "\x1b[2J")?;
+                self.renderer.request_clear();
                 self.resize_pending = false;
             }
+            self.renderer.blank_regions(&exposed);
             self.renderer.render(stdout, &dirty)?;
             self.record_render_metric(dirty.len());
             self.log_runtime_event(
@@ -1218,7 +1806,8 @@ impl RoomRuntime {
         for idx in 0..self.plugins.len() {
             let outcome = {
                 let entry = &mut self.plugins[idx];
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 entry.plugin.after_render(&mut ctx)?;
                 ctx.into_outcome()
             };
@@ -1235,9 +1824,43 @@ impl RoomRuntime {
         Ok(())
     }
 
+    /// The configured minimum size when the terminal is currently below it.
+    fn undersized(&self) -> Option<Size> {
+        self.config.min_size.filter(|min| {
+            self.current_size.width < min.width || self.current_size.height < min.height
+        })
+    }
+
+    fn render_size_notice(&mut self, stdout: &mut impl Write, min: Size) -> Result<()> {
+        let size = self.current_size;
+        let mut notice = ZoneState::new(Rect::new(0, 0, size.width, size.height));
+        notice.content = format!(
+            "terminal too small (need {}x{}, have {}x{})",
+            min.width, min.height, size.width, size.height
+        );
+        notice.format = ZoneFormat::new()
+            .overflow(Overflow::WordWrap)
+            .align(HorizontalAlign::Center)
+            .vertical_align(VerticalAlign::Middle);
+        if self.resize_pending {
+            self.renderer.request_clear();
+            self.resize_pending = false;
+        }
+        self.renderer
+            .render(stdout, &[("room::size_notice".to_string(), notice)])
+    }
+
     fn apply_outcome(&mut self, outcome: ContextOutcome) -> Result<()> {
         let ContextOutcome {
             zone_updates,
+            format_updates,
+            overlay_updates,
+            log_updates,
+            intrinsic_updates,
+            layout_update,
+            layout_edits,
+            timer_requests,
+            published,
             redraw_requested,
             exit_requested,
             cursor_hint,
@@ -1245,24 +1868,106 @@ impl RoomRuntime {
             error,
         } = outcome;
 
+        for request in timer_requests {
+            self.timers.apply(request);
+        }
+        self.pending_published.extend(published);
+
+        if let Some(layout) = layout_update
+            && let Err(err) = self.replace_layout(layout)
+        {
+            self.pending_errors.push(RuntimeError {
+                category: "layout".to_string(),
+                source: None,
+                message: err.to_string(),
+                recoverable: true,
+            });
+        }
+
+        if !layout_edits.is_empty() {
+            self.apply_layout_edits(layout_edits)?;
+        }
+
+        let mut intrinsic_changed = false;
+        for (zone, size) in intrinsic_updates {
+            intrinsic_changed |= self.intrinsic_sizes.insert(zone, size) != Some(size);
+        }
+        if intrinsic_changed {
+            match self
+                .layout
+                .solve_with_intrinsic(self.current_size, &self.intrinsic_sizes)
+            {
+                Ok(rects) => {
+                    if self.install_rects(rects) {
+                        self.redraw_requested = true;
+                    }
+                }
+                // Keep the current rects; the next successful solve catches up.
+                Err(err) => self.pending_errors.push(RuntimeError {
+                    category: "layout".to_string(),
+                    source: None,
+                    message: err.to_string(),
+                    recoverable: true,
+                }),
+            }
+        }
+
+        for update in overlay_updates {
+            let result = match update {
+                OverlayUpdate::Open {
+                    zone,
+                    rect,
+                    z_index,
+                } => self.registry.open_overlay(&zone, rect, z_index).map(|()| {
+                    self.rects.insert(zone, rect);
+                }),
+                OverlayUpdate::Close(zone) => self.registry.close_overlay(&zone).map(|()| {
+                    self.rects.remove(&zone);
+                }),
+            };
+            match result {
+                Ok(()) => self.redraw_requested = true,
+                Err(err) => self.pending_errors.push(RuntimeError {
+                    category: "overlay".to_string(),
+                    source: None,
+                    message: err.to_string(),
+                    recoverable: true,
+                }),
+            }
+        }
+
         let update_count = zone_updates.len();
         if update_count > 0 {
             for update in zone_updates {
-                let ZoneUpdate {
-                    zone,
-                    content,
-                    pre_rendered,
-                } = update;
-                if pre_rendered {
-                    self.registry.apply_pre_rendered(&zone, content)?;
-                } else {
-                    self.registry.apply_content(&zone, content)?;
+                let ZoneUpdate { zone, payload } = update;
+                match payload {
+                    ZonePayload::Text(content) => self.registry.apply_content(&zone, content)?,
+                    ZonePayload::PreRendered(content) => {
+                        self.registry.apply_pre_rendered(&zone, content)?
+                    }
+                    ZonePayload::Styled(content) => self.registry.apply_styled(&zone, content)?,
+                    ZonePayload::Append(line) => self.registry.append_log(&zone, line)?,
                 }
             }
             self.record_zone_updates_metric(update_count);
             self.redraw_requested = true;
         }
 
+        for update in log_updates {
+            match update {
+                LogUpdate::Capacity { zone, capacity } => {
+                    self.registry.set_log_capacity(&zone, capacity)?
+                }
+                LogUpdate::Scroll { zone, scroll } => {
+                    self.registry.scroll_log(&zone, scroll)?;
+                }
+            }
+        }
+
+        for (zone, format) in format_updates {
+            self.registry.apply_format(&zone, format)?;
+        }
+
         if redraw_requested {
             self.redraw_requested = true;
         }
@@ -1304,8 +2009,32 @@ impl RoomRuntime {
         Ok(())
     }
 
+    /// Default PageUp/PageDown handling: scroll the focused zone when it is a
+    /// log zone and no plugin consumed the key.
+    fn scroll_focused_log(&mut self, event: &RuntimeEvent) -> Result<bool> {
+        let RuntimeEvent::Key(key) = event else {
+            return Ok(false);
+        };
+        let scroll = match key.code {
+            KeyCode::PageUp => LogScroll::PageUp,
+            KeyCode::PageDown => LogScroll::PageDown,
+            _ => return Ok(false),
+        };
+        let Some(focused) = self.last_focus_entry.as_ref().map(|entry| entry.zone_id.clone())
+        else {
+            return Ok(false);
+        };
+        if !self.registry.is_log(&focused) {
+            return Ok(false);
+        }
+        if self.registry.scroll_log(&focused, scroll)? {
+            self.redraw_requested = true;
+        }
+        Ok(true)
+    }
+
     fn detect_focus_change(&mut self) -> Result<()> {
-        let ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+        let ctx = RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
         if let Ok(registry) = ensure_focus_registry(&ctx) {
             let current = registry.current();
             if current != self.last_focus_entry {
@@ -1418,6 +2147,39 @@ impl RoomRuntime {
             self.notify_plugins(|plugin, ctx| plugin.on_focus_change(ctx, &change_clone))?;
         }
 
+        let resizes = std::mem::take(&mut self.pending_zone_resizes);
+        for resized in resizes {
+            self.log_runtime_event(
+                LogLevel::Debug,
+                "zone_resized",
+                [
+                    json_kv("zone", json!(resized.zone.clone())),
+                    json_kv("width", json!(resized.new.width)),
+                    json_kv("height", json!(resized.new.height)),
+                ],
+            );
+            self.notify_plugins(|plugin, ctx| plugin.on_zone_resized(ctx, &resized))?;
+        }
+
+        let visibility = std::mem::take(&mut self.pending_visibility);
+        for change in visibility {
+            let (event, zone) = match &change {
+                ZoneVisibility::Hidden { zone } => ("zone_hidden", zone),
+                ZoneVisibility::Shown { zone, .. } => ("zone_shown", zone),
+            };
+            self.log_runtime_event(
+                LogLevel::Debug,
+                event,
+                [json_kv("zone", json!(zone.clone()))],
+            );
+            self.notify_plugins(|plugin, ctx| plugin.on_zone_visibility(ctx, &change))?;
+        }
+
+        // Inside an event the top-level dispatch delivers published events.
+        if self.dispatch_depth == 0 {
+            self.dispatch_published()?;
+        }
+
         Ok(())
     }
 
@@ -1484,17 +2246,15 @@ impl RoomRuntime {
     }
 
     fn handle_resize(&mut self, size: Size) -> Result<()> {
-        use std::io::Write;
-        let mut stdout = std::io::stdout();
-        write!(stdout, "\x1b[2J")?;
-        stdout.flush()?;
-
+        // The clear is deferred to the next frame so it lands in the same
+        // (synchronized) write as the repaint instead of flashing a blank screen.
         self.current_size = size;
-        let rects = self.layout.solve(size)?;
-        self.rects = rects;
-        self.registry.sync_layout(&self.rects);
-        self.redraw_requested = true;
-        self.resize_pending = true;
+        self.renderer.resize(size);
+        let rects = self
+            .layout
+            .solve_with_intrinsic(size, &self.intrinsic_sizes)?;
+        self.install_rects(rects);
+        self.request_full_repaint();
         self.log_runtime_event(
             LogLevel::Info,
             "resized",
@@ -1503,18 +2263,103 @@ impl RoomRuntime {
                 json_kv("height", json!(size.height)),
             ],
         );
+        if self.config.log_layout_warnings && self.config.logger.is_some() {
+            for warning in self
+                .layout
+                .diagnose_with_intrinsic(size, &self.intrinsic_sizes)
+            {
+                self.log_runtime_event(
+                    LogLevel::Warn,
+                    "layout_warning",
+                    [json_kv("warning", json!(warning.to_string()))],
+                );
+            }
+        }
         Ok(())
     }
 
     pub(crate) fn apply_screen_layout(&mut self, layout: impl Layout + 'static) -> Result<()> {
-        let rects = layout.solve(self.current_size)?;
-        self.layout = Box::new(layout);
-        self.rects = rects;
-        self.registry.sync_layout(&self.rects);
+        self.replace_layout(Box::new(layout))
+    }
+
+    /// Apply queued edits to the active layout in order and re-solve it once.
+    /// Edits are not atomic: a rejected edit is reported as a recoverable
+    /// `layout` error while the edits around it stay applied.
+    fn apply_layout_edits(&mut self, edits: Vec<LayoutEdit>) -> Result<()> {
+        let mut edited = false;
+        for edit in edits {
+            match self.layout.apply_edit(edit) {
+                Ok(()) => edited = true,
+                Err(err) => self.pending_errors.push(RuntimeError {
+                    category: "layout".to_string(),
+                    source: None,
+                    message: err.to_string(),
+                    recoverable: true,
+                }),
+            }
+        }
+        if edited {
+            let rects = self
+                .layout
+                .solve_with_intrinsic(self.current_size, &self.intrinsic_sizes)?;
+            if self.install_rects(rects) {
+                self.redraw_requested = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Solve and adopt a new layout. The current layout stays in place when the
+    /// new one fails to solve.
+    fn replace_layout(&mut self, layout: Box<dyn Layout>) -> Result<()> {
+        let rects = layout.solve_with_intrinsic(self.current_size, &self.intrinsic_sizes)?;
+        self.layout = layout;
+        self.install_rects(rects);
         self.redraw_requested = true;
         Ok(())
     }
 
+    /// Adopt freshly solved layout rects. Open overlays keep their placement
+    /// and stay visible to plugins through `RuntimeContext::rect`; zones whose
+    /// rect changed are announced through `on_zone_resized`, and zones the
+    /// layout dropped or restored through `on_zone_visibility`. Only the zones
+    /// involved are repainted, over blanks where they used to be. Returns
+    /// whether any existing zone moved, changed size, or changed visibility.
+    fn install_rects(&mut self, rects: HashMap<String, Rect>) -> bool {
+        self.rects = rects;
+        self.zone_constraints = self
+            .layout
+            .describe_with_intrinsic(self.current_size, &self.intrinsic_sizes);
+        let changes = self.registry.sync_layout(&self.rects);
+        let changed = !changes.is_empty();
+        self.pending_zone_resizes.extend(
+            changes
+                .resized
+                .into_iter()
+                .map(|(zone, old, new)| ZoneResized { zone, old, new }),
+        );
+        self.pending_visibility.extend(
+            changes
+                .hidden
+                .into_iter()
+                .map(|zone| ZoneVisibility::Hidden { zone }),
+        );
+        for zone in changes.shown {
+            let rect = self.rects[&zone];
+            self.pending_visibility.push(ZoneVisibility::Shown { zone, rect });
+        }
+        self.rects.extend(self.registry.overlay_rects());
+        changed
+    }
+
+    /// Clear the screen on the next frame and repaint every zone, so cells a
+    /// zone no longer owns do not keep stale content.
+    fn request_full_repaint(&mut self) {
+        self.registry.mark_all_dirty();
+        self.redraw_requested = true;
+        self.resize_pending = true;
+    }
+
     fn bootstrap(&mut self, stdout: &mut impl Write) -> Result<()> {
         self.bootstrap_prepare()?;
         self.render_if_needed(stdout)
@@ -1528,6 +2373,8 @@ impl RoomRuntime {
         self.fatal_active = false;
         self.pending_cursor_events.clear();
         self.pending_focus_changes.clear();
+        self.pending_zone_resizes.clear();
+        self.pending_visibility.clear();
         self.pending_errors.clear();
         self.last_focus_entry = None;
         self.ensure_metrics_initialized();
@@ -1553,7 +2400,8 @@ impl RoomRuntime {
                 let entry = &mut self.plugins[idx];
                 let name = entry.name.clone();
                 let priority = entry.priority;
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 entry.plugin.init(&mut ctx)?;
                 (name, priority, ctx.into_outcome())
             };
@@ -1583,8 +2431,11 @@ impl RoomRuntime {
         let Some(zone) = self.config.default_focus_zone.clone() else {
             return Ok(());
         };
+        self.focus_zone(zone)
+    }
 
-        let ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+    fn focus_zone(&mut self, zone: String) -> Result<()> {
+        let ctx = RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
         let registry = ensure_focus_registry(&ctx)
             .map_err(|err| LayoutError::Backend(format!("focus registry: {err}")))?;
         let mut controller = FocusController::new(RUNTIME_FOCUS_OWNER, registry);
@@ -1666,7 +2517,8 @@ impl RoomRuntime {
         for idx in 0..self.plugins.len() {
             let outcome = {
                 let entry = &mut self.plugins[idx];
-                let mut ctx = RuntimeContext::new(&self.rects, &self.shared_state);
+                let mut ctx =
+                    RuntimeContext::new(&self.rects, &self.zone_constraints, &self.shared_state);
                 hook(entry.plugin.as_mut(), &mut ctx)?;
                 ctx.into_outcome()
             };
@@ -1756,8 +2608,12 @@ impl RoomRuntime {
             RuntimeEvent::CursorHidden(_) => "cursor_hidden",
             RuntimeEvent::FocusChanged(_) => "focus_changed",
             RuntimeEvent::Tick { .. } => "tick",
+            RuntimeEvent::Timer { .. } => "timer",
             RuntimeEvent::Key(_) => "key",
             RuntimeEvent::Mouse(_) => "mouse",
+            RuntimeEvent::ZoneMouse(_) => "zone_mouse",
+            RuntimeEvent::Action(_) => "action",
+            RuntimeEvent::Custom(_) => "custom",
             RuntimeEvent::Paste(_) => "paste",
             RuntimeEvent::FocusGained => "focus_gained",
             RuntimeEvent::FocusLost => "focus_lost",
@@ -1822,6 +2678,7 @@ impl<'a, W: Write> BootstrapControls<'a, W> {
             .dispatch_event(RuntimeEvent::Tick { elapsed })?;
         self.runtime
             .audit_record(RuntimeAuditStage::TickDispatched, []);
+        self.runtime.advance_timers(elapsed)?;
         self.runtime.render_if_needed(self.stdout)?;
         self.first_frame_presented = true;
         Ok(())
@@ -1875,10 +2732,13 @@ impl<'a, W: Write> BootstrapControls<'a, W> {
 #[cfg(test)]
 mod bootstrap_tests {
     use super::*;
+    use crate::logging::{LogEvent, LogSink, LoggingResult};
     use crate::{
-        AnsiRenderer, Constraint, Direction, LayoutNode, LayoutTree, RoomPlugin, RuntimeConfig,
-        Size,
+        AnsiRenderer, Breakpoint, Constraint, Direction, GridArea, GridLayout, GridSize,
+        LayoutNode, LayoutTree, ResponsiveLayout, RoomPlugin, RuntimeConfig, Size,
+        VirtualTerminal,
     };
+    use crossterm::event::KeyModifiers;
     use std::time::Duration;
 
     const TEST_ZONE: &str = "app:test";
@@ -1954,7 +2814,1083 @@ mod bootstrap_tests {
                 .expect("ticks");
             controls.finish().expect("finish");
         }
-        let output = String::from_utf8(buffer).expect("utf8");
-        assert!(output.contains("Ticks observed: 3"));
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        terminal.write_all(&buffer).expect("replay");
+        assert_eq!(terminal.row_text(0), "Ticks observed: 3");
+    }
+
+    struct PopupPlugin;
+
+    impl RoomPlugin for PopupPlugin {
+        fn name(&self) -> &str {
+            "popup_plugin"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if let RuntimeEvent::Key(key) = event {
+                match key.code {
+                    crossterm::event::KeyCode::Char('o') => {
+                        ctx.open_overlay("popup", Rect::new(4, 0, 5, 2), 10);
+                        ctx.set_zone("popup", "POPUP");
+                    }
+                    crossterm::event::KeyCode::Char('c') => ctx.close_overlay("popup"),
+                    _ => {}
+                }
+            }
+            Ok(EventFlow::Continue)
+        }
+    }
+
+    #[test]
+    fn overlays_cover_and_restore_base_zones() {
+        let mut runtime = build_runtime();
+        runtime.register_plugin_with_priority(PopupPlugin, 10);
+        let key = |ch| {
+            RuntimeEvent::Key(KeyEvent::new(
+                crossterm::event::KeyCode::Char(ch),
+                crossterm::event::KeyModifiers::NONE,
+            ))
+        };
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(&mut terminal, [key('o'), key('c')])
+            .expect("scripted run");
+
+        let frames = terminal.frames();
+        // Frame 2: the key updates the base zone and the popup stays on top of it.
+        assert_eq!(frames[1], "Key POPUPved\n\n\n");
+        assert_eq!(frames[2], "Key received\n\n\n");
+    }
+
+    /// Records the category and recoverability of every reported error.
+    #[derive(Default)]
+    struct ErrorRecorder {
+        errors: Arc<Mutex<Vec<(String, bool)>>>,
+    }
+
+    impl RoomPlugin for ErrorRecorder {
+        fn name(&self) -> &str {
+            "error_recorder"
+        }
+
+        fn on_error(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            error: &mut RuntimeError,
+        ) -> Result<()> {
+            let mut errors = self.errors.lock().unwrap();
+            errors.push((error.category.clone(), error.recoverable));
+            Ok(())
+        }
+    }
+
+    /// Opens a layout zone as an overlay and closes one that is not open.
+    struct OverlayMisusePlugin;
+
+    impl RoomPlugin for OverlayMisusePlugin {
+        fn name(&self) -> &str {
+            "overlay_misuse"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if matches!(event, RuntimeEvent::Key(_)) {
+                ctx.open_overlay(TEST_ZONE, Rect::new(0, 0, 4, 1), 5);
+                ctx.close_overlay("app:missing");
+            }
+            Ok(EventFlow::Continue)
+        }
+    }
+
+    #[test]
+    fn overlay_misuse_is_reported_as_recoverable() {
+        let mut runtime = build_runtime();
+        let recorder = ErrorRecorder::default();
+        let errors = Arc::clone(&recorder.errors);
+        runtime.register_plugin(recorder);
+        runtime.register_plugin_with_priority(OverlayMisusePlugin, -1);
+
+        let key = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE);
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Key(key)])
+            .expect("scripted run");
+
+        assert_eq!(
+            *errors.lock().unwrap(),
+            vec![("overlay".to_string(), true), ("overlay".to_string(), true)]
+        );
+        // The runtime keeps going and later plugins still handle the key.
+        assert_eq!(terminal.row_text(0), "Key received");
+    }
+
+    struct LogPlugin {
+        lines: usize,
+    }
+
+    impl RoomPlugin for LogPlugin {
+        fn name(&self) -> &str {
+            "log_plugin"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            let registry = ensure_focus_registry(ctx).expect("focus registry");
+            FocusController::new("log_plugin", registry).focus(TEST_ZONE);
+            Ok(())
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if let RuntimeEvent::Key(key) = event
+                && key.code == KeyCode::Char('a')
+            {
+                self.lines += 1;
+                ctx.append_zone(TEST_ZONE, format!("line {}", self.lines));
+                return Ok(EventFlow::Consumed);
+            }
+            Ok(EventFlow::Continue)
+        }
+    }
+
+    #[test]
+    fn log_zones_follow_tail_and_page_back() {
+        let layout = LayoutTree::new(LayoutNode {
+            id: "app:root".into(),
+            direction: Direction::Column,
+            constraints: vec![Constraint::Flex(1)],
+            children: vec![LayoutNode::leaf(TEST_ZONE)],
+            gap: 0,
+            padding: 0,
+        });
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(40, 4))
+                .expect("runtime");
+        runtime.register_plugin(LogPlugin { lines: 0 });
+        let key = |code| RuntimeEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let mut events = vec![key(KeyCode::Char('a')); 6];
+        events.push(key(KeyCode::PageUp));
+
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(&mut terminal, events)
+            .expect("scripted run");
+
+        let frames = terminal.frames();
+        assert_eq!(frames[6], "line 3\nline 4\nline 5\nline 6");
+        assert_eq!(frames[7], "line 1\nline 2\nline 3\nline 4");
+    }
+
+    #[derive(Default)]
+    struct ResizeWatcher {
+        resized: Arc<Mutex<Vec<ZoneResized>>>,
+    }
+
+    impl RoomPlugin for ResizeWatcher {
+        fn name(&self) -> &str {
+            "resize_watcher"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone(TEST_ZONE, "abcdefgh");
+            Ok(())
+        }
+
+        fn on_zone_resized(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            resized: &ZoneResized,
+        ) -> Result<()> {
+            self.resized.lock().unwrap().push(resized.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resize_rewraps_content_and_notifies_plugins() {
+        let layout = LayoutTree::new(LayoutNode {
+            id: "app:root".into(),
+            direction: Direction::Column,
+            constraints: vec![Constraint::Flex(1)],
+            children: vec![LayoutNode::leaf(TEST_ZONE)],
+            gap: 0,
+            padding: 0,
+        });
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(20, 3))
+                .expect("runtime");
+        let watcher = ResizeWatcher::default();
+        let resized = Arc::clone(&watcher.resized);
+        runtime.register_plugin(watcher);
+
+        let mut terminal = VirtualTerminal::new(Size::new(20, 3));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Resize(Size::new(5, 3))])
+            .expect("scripted run");
+
+        assert_eq!(terminal.frames()[0], "abcdefgh\n\n");
+        assert_eq!(terminal.screen_text(), "abcde\nfgh\n");
+        let resized = resized.lock().unwrap();
+        let zone = resized
+            .iter()
+            .find(|resized| resized.zone == TEST_ZONE)
+            .expect("zone resize notified");
+        assert_eq!(zone.old, Rect::new(0, 0, 20, 3));
+        assert_eq!(zone.new, Rect::new(0, 0, 5, 3));
+    }
+
+    #[derive(Clone, Default)]
+    struct MemorySink(Arc<Mutex<Vec<LogEvent>>>);
+
+    impl LogSink for MemorySink {
+        fn log(&self, event: &LogEvent) -> LoggingResult<()> {
+            self.0.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resize_logs_layout_warnings_when_enabled() {
+        let layout = LayoutTree::new(LayoutNode::container(
+            "app:root",
+            Direction::Row,
+            vec![Constraint::Fixed(8), Constraint::Flex(1)],
+            vec![LayoutNode::leaf("app:nav"), LayoutNode::leaf(TEST_ZONE)],
+        ));
+        let sink = MemorySink::default();
+        let config = RuntimeConfig {
+            logger: Some(Logger::new(sink.clone())),
+            log_layout_warnings: true,
+            ..RuntimeConfig::default()
+        };
+        let mut runtime = RoomRuntime::with_config(
+            layout,
+            AnsiRenderer::with_default(),
+            Size::new(20, 3),
+            config,
+        )
+        .expect("runtime");
+
+        let mut terminal = VirtualTerminal::new(Size::new(20, 3));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Resize(Size::new(6, 3))])
+            .expect("scripted run");
+
+        let warnings: Vec<String> = sink
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.message == "layout_warning")
+            .map(|event| event.fields["warning"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "`app:root` needs 8 cells for fixed sizes but has 6".to_string(),
+                format!("zone `{TEST_ZONE}` collapsed to 0x3"),
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct RenderCounter {
+        renders: Arc<Mutex<usize>>,
+    }
+
+    impl RoomPlugin for RenderCounter {
+        fn name(&self) -> &str {
+            "render_counter"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone(TEST_ZONE, "ready");
+            Ok(())
+        }
+
+        fn before_render(&mut self, _ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            *self.renders.lock().unwrap() += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn undersized_terminal_shows_notice_until_it_grows() {
+        let layout = LayoutTree::new(LayoutNode::container(
+            "app:root",
+            Direction::Column,
+            vec![Constraint::Flex(1)],
+            vec![LayoutNode::leaf(TEST_ZONE)],
+        ));
+        let config = RuntimeConfig {
+            min_size: Some(Size::new(40, 3)),
+            ..RuntimeConfig::default()
+        };
+        let mut runtime = RoomRuntime::with_config(
+            layout,
+            AnsiRenderer::with_default(),
+            Size::new(30, 3),
+            config,
+        )
+        .expect("runtime");
+        let counter = RenderCounter::default();
+        let renders = Arc::clone(&counter.renders);
+        runtime.register_plugin(counter);
+
+        let mut terminal = VirtualTerminal::new(Size::new(30, 3));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Resize(Size::new(40, 3))])
+            .expect("scripted run");
+
+        assert_eq!(
+            terminal.frames()[0],
+            "terminal too small (need 40x3,\n          have 30x3)\n"
+        );
+        assert_eq!(terminal.screen_text(), "ready\n\n");
+        assert_eq!(*renders.lock().unwrap(), 1);
+    }
+
+    struct MenuPlugin;
+
+    impl RoomPlugin for MenuPlugin {
+        fn name(&self) -> &str {
+            "menu"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone("app:menu", "File");
+            ctx.set_intrinsic_size("app:menu", Size::new(4, 1));
+            ctx.set_zone("app:main", "Body");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn intrinsic_sizes_drive_auto_grid_tracks() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Auto)
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("app:menu", GridArea::cell(0, 0)).unwrap();
+        grid.place("app:main", GridArea::cell(0, 1)).unwrap();
+
+        let mut runtime =
+            RoomRuntime::new(grid, AnsiRenderer::with_default(), Size::new(20, 2))
+                .expect("runtime");
+        runtime.register_plugin(MenuPlugin);
+
+        let mut terminal = VirtualTerminal::new(Size::new(20, 2));
+        runtime
+            .run_scripted(&mut terminal, std::iter::empty())
+            .expect("scripted run");
+
+        assert_eq!(terminal.screen_text(), "FileBody\n");
+    }
+
+    /// Grid that cannot be solved once any intrinsic size is reported.
+    struct RejectsIntrinsic(GridLayout);
+
+    impl Layout for RejectsIntrinsic {
+        fn solve(&self, size: Size) -> Result<HashMap<String, Rect>> {
+            Layout::solve(&self.0, size)
+        }
+
+        fn solve_with_intrinsic(
+            &self,
+            size: Size,
+            intrinsic: &HashMap<String, Size>,
+        ) -> Result<HashMap<String, Rect>> {
+            if !intrinsic.is_empty() {
+                return Err(LayoutError::Backend("intrinsic sizes rejected".into()));
+            }
+            self.solve(size)
+        }
+    }
+
+    #[test]
+    fn failed_intrinsic_resolves_keep_the_current_layout() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(6))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("app:menu", GridArea::cell(0, 0)).unwrap();
+        grid.place("app:main", GridArea::cell(0, 1)).unwrap();
+
+        let mut runtime = RoomRuntime::new(
+            RejectsIntrinsic(grid),
+            AnsiRenderer::with_default(),
+            Size::new(20, 2),
+        )
+        .expect("runtime");
+        let recorder = ErrorRecorder::default();
+        let errors = Arc::clone(&recorder.errors);
+        runtime.register_plugin(recorder);
+        runtime.register_plugin(MenuPlugin);
+
+        let mut terminal = VirtualTerminal::new(Size::new(20, 2));
+        runtime
+            .run_scripted(&mut terminal, std::iter::empty())
+            .expect("scripted run");
+
+        assert_eq!(*errors.lock().unwrap(), vec![("layout".to_string(), true)]);
+        assert_eq!(terminal.screen_text(), "File  Body\n");
+    }
+
+    #[derive(Default)]
+    struct VisibilityWatcher {
+        changes: Arc<Mutex<Vec<ZoneVisibility>>>,
+    }
+
+    impl RoomPlugin for VisibilityWatcher {
+        fn name(&self) -> &str {
+            "visibility_watcher"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone("app:side", "Side");
+            ctx.set_zone("app:main", "Main");
+            Ok(())
+        }
+
+        fn on_zone_visibility(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            change: &ZoneVisibility,
+        ) -> Result<()> {
+            self.changes.lock().unwrap().push(change.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn responsive_layout_hides_and_restores_zones() {
+        let mut narrow = GridLayout::new();
+        narrow.add_col(GridSize::flex(1)).add_row(GridSize::flex(1));
+        narrow.place("app:main", GridArea::cell(0, 0)).unwrap();
+        let mut wide = GridLayout::new();
+        wide.add_col(GridSize::Fixed(6))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        wide.place("app:side", GridArea::cell(0, 0)).unwrap();
+        wide.place("app:main", GridArea::cell(0, 1)).unwrap();
+        let layout = ResponsiveLayout::new(narrow).with_breakpoint(Breakpoint::min_width(30), wide);
+
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(40, 1))
+                .expect("runtime");
+        let watcher = VisibilityWatcher::default();
+        let changes = Arc::clone(&watcher.changes);
+        runtime.register_plugin(watcher);
+
+        let mut terminal = VirtualTerminal::new(Size::new(40, 1));
+        runtime
+            .run_scripted(
+                &mut terminal,
+                [
+                    RuntimeEvent::Resize(Size::new(20, 1)),
+                    RuntimeEvent::Resize(Size::new(40, 1)),
+                ],
+            )
+            .expect("scripted run");
+
+        assert_eq!(terminal.frames(), &["Side  Main", "Main", "Side  Main"]);
+        assert_eq!(
+            changes.lock().unwrap().as_slice(),
+            &[
+                ZoneVisibility::Hidden {
+                    zone: "app:side".to_string(),
+                },
+                ZoneVisibility::Shown {
+                    zone: "app:side".to_string(),
+                    rect: Rect::new(0, 0, 6, 1),
+                },
+            ]
+        );
+    }
+
+    struct PaneToggle;
+
+    impl RoomPlugin for PaneToggle {
+        fn name(&self) -> &str {
+            "pane_toggle"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            let RuntimeEvent::Key(key) = event else {
+                return Ok(EventFlow::Continue);
+            };
+            match key.code {
+                KeyCode::Char('c') => {
+                    ctx.edit_layout(LayoutEdit::remove("app:side"));
+                    ctx.edit_layout(LayoutEdit::place("app:main", GridArea::span_cols(0, 0..2)));
+                }
+                KeyCode::Char('o') => {
+                    ctx.edit_layout(LayoutEdit::place("app:main", GridArea::cell(0, 1)));
+                    ctx.edit_layout(LayoutEdit::place("app:side", GridArea::cell(0, 0)));
+                    ctx.edit_layout(LayoutEdit::SetCol {
+                        index: 0,
+                        size: GridSize::Fixed(4),
+                    });
+                }
+                _ => return Ok(EventFlow::Continue),
+            }
+            Ok(EventFlow::Consumed)
+        }
+    }
+
+    #[test]
+    fn layout_edits_close_and_reopen_panes() {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(6))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("app:side", GridArea::cell(0, 0)).unwrap();
+        grid.place("app:main", GridArea::cell(0, 1)).unwrap();
+
+        let mut runtime =
+            RoomRuntime::new(grid, AnsiRenderer::with_default(), Size::new(20, 1))
+                .expect("runtime");
+        runtime.register_plugin(VisibilityWatcher::default());
+        runtime.register_plugin(PaneToggle);
+
+        let key = |ch| RuntimeEvent::Key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        let mut terminal = VirtualTerminal::new(Size::new(20, 1));
+        runtime
+            .run_scripted(&mut terminal, [key('c'), key('o')])
+            .expect("scripted run");
+
+        assert_eq!(terminal.frames(), &["Side  Main", "Main", "SideMain"]);
+    }
+
+    #[derive(Default)]
+    struct MouseRecorder {
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RoomPlugin for MouseRecorder {
+        fn name(&self) -> &str {
+            "mouse_recorder"
+        }
+
+        fn on_event(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if let RuntimeEvent::ZoneMouse(mouse) = event {
+                self.seen.lock().unwrap().push(format!(
+                    "{} {},{} {:?}",
+                    mouse.zone_id, mouse.local_row, mouse.local_col, mouse.kind
+                ));
+            }
+            Ok(EventFlow::Continue)
+        }
+
+        fn on_focus_change(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            change: &FocusChange,
+        ) -> Result<()> {
+            if let Some(target) = &change.to {
+                self.seen
+                    .lock()
+                    .unwrap()
+                    .push(format!("focus {}", target.zone));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn mouse_events_resolve_to_zones_and_focus_on_click() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(6))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("app:side", GridArea::cell(0, 0)).unwrap();
+        grid.place("app:main", GridArea::cell(0, 1)).unwrap();
+
+        let config = RuntimeConfig {
+            focus_on_click: true,
+            ..RuntimeConfig::default()
+        };
+        let mut runtime =
+            RoomRuntime::with_config(grid, AnsiRenderer::with_default(), Size::new(20, 3), config)
+                .expect("runtime");
+        let recorder = MouseRecorder::default();
+        let seen = Arc::clone(&recorder.seen);
+        runtime.register_plugin(recorder);
+
+        let mouse = |kind, column, row| {
+            RuntimeEvent::Mouse(MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let left = MouseButton::Left;
+        let mut terminal = VirtualTerminal::new(Size::new(20, 3));
+        runtime
+            .run_scripted(
+                &mut terminal,
+                [
+                    mouse(MouseEventKind::Down(left), 8, 1),
+                    mouse(MouseEventKind::Drag(left), 9, 1),
+                    mouse(MouseEventKind::Drag(left), 2, 5),
+                    mouse(MouseEventKind::Up(left), 2, 5),
+                    mouse(MouseEventKind::ScrollDown, 1, 2),
+                    mouse(MouseEventKind::Moved, 1, 2),
+                ],
+            )
+            .expect("scripted run");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                "app:main 1,2 Click(Left)",
+                "focus app:main",
+                "app:main 1,3 DragStart(Left)",
+                "app:main 2,0 DragMove(Left)",
+                "app:main 2,0 DragEnd(Left)",
+                "app:side 2,1 ScrollDown",
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct ActionRecorder {
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RoomPlugin for ActionRecorder {
+        fn name(&self) -> &str {
+            "action_recorder"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            let entry = match event {
+                RuntimeEvent::Action(action) => {
+                    if action.is("insert_mode") {
+                        keymap::ensure_keymap(ctx).unwrap().set_mode("insert");
+                    }
+                    format!("{} ({})", action.name, action.keys)
+                }
+                RuntimeEvent::Key(key) => format!("key {:?}", key.code),
+                _ => return Ok(EventFlow::Continue),
+            };
+            self.seen.lock().unwrap().push(entry);
+            Ok(EventFlow::Consumed)
+        }
+    }
+
+    #[test]
+    fn keymap_turns_sequences_into_actions() {
+        let mut runtime = build_runtime();
+        let keymap = Keymap::new();
+        let normal = keymap::KeyScope::default().mode(keymap::DEFAULT_MODE);
+        keymap.bind_scoped("g g", "top", normal.clone()).unwrap();
+        keymap.bind_scoped("i", "insert_mode", normal).unwrap();
+        runtime
+            .shared_state_handle()
+            .insert_arc(Arc::new(keymap))
+            .unwrap();
+        let recorder = ActionRecorder::default();
+        let seen = Arc::clone(&recorder.seen);
+        runtime.register_plugin_with_priority(recorder, -1);
+
+        let key = |ch| RuntimeEvent::Key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(
+                &mut terminal,
+                [key('g'), key('g'), key('x'), key('i'), key('i'), key('g')],
+            )
+            .expect("scripted run");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                "top (g g)",
+                "key Char('x')",
+                "insert_mode (i)",
+                "key Char('i')",
+                "key Char('g')",
+            ]
+        );
+    }
+
+    #[test]
+    fn broken_and_timed_out_sequences_reach_plugins_as_keys() {
+        let mut runtime = build_runtime();
+        let keymap = Keymap::new();
+        keymap.bind("g g", "top").unwrap();
+        keymap.set_timeout(Duration::from_millis(500));
+        runtime
+            .shared_state_handle()
+            .insert_arc(Arc::new(keymap))
+            .unwrap();
+        let recorder = ActionRecorder::default();
+        let seen = Arc::clone(&recorder.seen);
+        runtime.register_plugin_with_priority(recorder, -1);
+
+        let key = |ch| RuntimeEvent::Key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        let tick = |ms| RuntimeEvent::Tick {
+            elapsed: Duration::from_millis(ms),
+        };
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(
+                &mut terminal,
+                [
+                    key('g'),
+                    key('h'),
+                    key('g'),
+                    tick(600),
+                    key('g'),
+                    tick(300),
+                    key('g'),
+                ],
+            )
+            .expect("scripted run");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                "key Char('g')",
+                "key Char('h')",
+                "key Char('g')",
+                "top (g g)",
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct TimerPlugin {
+        spinner: Option<TimerHandle>,
+        spins: usize,
+        toast: bool,
+    }
+
+    impl TimerPlugin {
+        fn show(&self, ctx: &mut RuntimeContext<'_>) {
+            let toast = if self.toast { " saved" } else { "" };
+            ctx.set_zone(TEST_ZONE, format!("spins {}{toast}", self.spins));
+        }
+    }
+
+    impl RoomPlugin for TimerPlugin {
+        fn name(&self) -> &str {
+            "timer_plugin"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            self.spinner = Some(ctx.schedule_every(Duration::from_millis(100), "spin"));
+            ctx.schedule_after(Duration::from_millis(250), "toast");
+            self.toast = true;
+            self.show(ctx);
+            Ok(())
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            let RuntimeEvent::Timer { handle, token } = event else {
+                return Ok(EventFlow::Continue);
+            };
+            match token.as_str() {
+                "spin" => {
+                    assert_eq!(Some(*handle), self.spinner);
+                    self.spins += 1;
+                    if self.spins == 3 {
+                        ctx.cancel_timer(*handle);
+                    }
+                }
+                "toast" => self.toast = false,
+                _ => return Ok(EventFlow::Continue),
+            }
+            self.show(ctx);
+            Ok(EventFlow::Consumed)
+        }
+    }
+
+    #[test]
+    fn timers_fire_from_scripted_ticks_until_cancelled() {
+        let layout = LayoutTree::new(LayoutNode::container(
+            "app:root",
+            Direction::Column,
+            vec![Constraint::Flex(1)],
+            vec![LayoutNode::leaf(TEST_ZONE)],
+        ));
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(20, 1))
+                .expect("runtime");
+        runtime.register_plugin(TimerPlugin::default());
+
+        let tick = || RuntimeEvent::Tick {
+            elapsed: Duration::from_millis(100),
+        };
+        let mut terminal = VirtualTerminal::new(Size::new(20, 1));
+        runtime
+            .run_scripted(&mut terminal, (0..5).map(|_| tick()))
+            .expect("scripted run");
+
+        assert_eq!(
+            terminal.frames(),
+            &["spins 0 saved", "spins 1 saved", "spins 2 saved", "spins 3"]
+        );
+    }
+
+    struct WorkerPlugin;
+
+    impl RoomPlugin for WorkerPlugin {
+        fn name(&self) -> &str {
+            "worker_plugin"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if let RuntimeEvent::Custom(custom) = event
+                && let Some(rows) = custom.payload::<usize>()
+            {
+                ctx.set_zone(TEST_ZONE, format!("{}: {rows} rows", custom.name));
+                return Ok(EventFlow::Consumed);
+            }
+            Ok(EventFlow::Continue)
+        }
+    }
+
+    #[test]
+    fn event_sender_delivers_custom_events_from_threads() {
+        let mut runtime = build_runtime();
+        runtime.register_plugin_with_priority(WorkerPlugin, -1);
+        let sender = runtime.event_sender();
+        std::thread::spawn(move || {
+            sender
+                .send(external::CustomEvent::with_payload("fetched", 42usize))
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::FocusGained])
+            .expect("scripted run");
+
+        assert_eq!(terminal.row_text(0), "fetched: 42 rows");
+        let sender = runtime.event_sender();
+        drop(runtime);
+        assert!(sender.notify("late").is_err());
+    }
+
+    struct Saved {
+        path: String,
+    }
+
+    struct PublisherPlugin;
+
+    impl RoomPlugin for PublisherPlugin {
+        fn name(&self) -> &str {
+            "publisher_plugin"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if !matches!(event, RuntimeEvent::Key(_)) {
+                return Ok(EventFlow::Continue);
+            }
+            ctx.publish(Saved {
+                path: "notes.txt".to_string(),
+            });
+            Ok(EventFlow::Consumed)
+        }
+    }
+
+    struct SubscriberPlugin {
+        name: &'static str,
+        seen: Arc<Mutex<Vec<String>>>,
+        flow: EventFlow,
+    }
+
+    impl RoomPlugin for SubscriberPlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn on_event(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            let Some(saved) = event.custom::<Saved>() else {
+                return Ok(EventFlow::Continue);
+            };
+            let mut seen = self.seen.lock().unwrap();
+            seen.push(format!("{}:{}", self.name, saved.path));
+            Ok(self.flow)
+        }
+    }
+
+    #[test]
+    fn published_events_reach_subscribers_in_priority_order() {
+        let mut runtime = build_runtime();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for (name, priority, flow) in [
+            ("late", 5, EventFlow::Continue),
+            ("editor", -1, EventFlow::Consumed),
+            ("audit", -5, EventFlow::Continue),
+        ] {
+            let seen = Arc::clone(&seen);
+            runtime.register_plugin_with_priority(SubscriberPlugin { name, seen, flow }, priority);
+        }
+        runtime.register_plugin(PublisherPlugin);
+
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        let key = KeyEvent::new(
+            crossterm::event::KeyCode::Char('s'),
+            crossterm::event::KeyModifiers::CONTROL,
+        );
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Key(key)])
+            .expect("scripted run");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["audit:notes.txt", "editor:notes.txt"]
+        );
+    }
+
+    struct Hop(String);
+
+    /// Publishes a `Hop` per key press and `fanout` more for every hop it
+    /// receives, until labels reach `max_len`.
+    struct HopPlugin {
+        fanout: usize,
+        max_len: usize,
+        seen: Arc<Mutex<Vec<String>>>,
+        errors: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RoomPlugin for HopPlugin {
+        fn name(&self) -> &str {
+            "hop_plugin"
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            let label = match event {
+                RuntimeEvent::Key(_) => {
+                    ctx.publish(Hop("r".to_string()));
+                    return Ok(EventFlow::Consumed);
+                }
+                _ => match event.custom::<Hop>() {
+                    Some(Hop(label)) => label,
+                    None => return Ok(EventFlow::Continue),
+                },
+            };
+            self.seen.lock().unwrap().push(label.clone());
+            if label.len() < self.max_len {
+                for branch in ["a", "b"].iter().take(self.fanout) {
+                    ctx.publish(Hop(format!("{label}{branch}")));
+                }
+            }
+            Ok(EventFlow::Consumed)
+        }
+
+        fn on_error(
+            &mut self,
+            _ctx: &mut RuntimeContext<'_>,
+            error: &mut RuntimeError,
+        ) -> Result<()> {
+            self.errors.lock().unwrap().push(error.category.clone());
+            Ok(())
+        }
+    }
+
+    fn run_hops(fanout: usize, max_len: usize) -> (Vec<String>, Vec<String>) {
+        let mut runtime = build_runtime();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        runtime.register_plugin_with_priority(
+            HopPlugin {
+                fanout,
+                max_len,
+                seen: Arc::clone(&seen),
+                errors: Arc::clone(&errors),
+            },
+            -1,
+        );
+        let key = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE);
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Key(key)])
+            .expect("scripted run");
+        let seen = seen.lock().unwrap().clone();
+        let errors = errors.lock().unwrap().clone();
+        (seen, errors)
+    }
+
+    #[test]
+    fn published_events_are_delivered_in_publish_order() {
+        let (seen, errors) = run_hops(2, 3);
+        assert_eq!(seen, ["r", "ra", "rb", "raa", "rab", "rba", "rbb"]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn endless_publishing_is_cut_off() {
+        let (seen, errors) = run_hops(1, usize::MAX);
+        assert_eq!(seen.len(), MAX_PUBLISHED_PER_EVENT);
+        assert_eq!(errors, ["publish"]);
+    }
+
+    #[test]
+    fn run_scripted_renders_to_virtual_terminal() {
+        let mut runtime = build_runtime();
+        let mut terminal = VirtualTerminal::new(Size::new(40, 4));
+        let key = KeyEvent::new(
+            crossterm::event::KeyCode::Char('x'),
+            crossterm::event::KeyModifiers::NONE,
+        );
+        runtime
+            .run_scripted(&mut terminal, [RuntimeEvent::Key(key)])
+            .expect("scripted run");
+
+        assert_eq!(terminal.row_text(0), "Key received");
+        assert_eq!(
+            terminal.frames().first().map(String::as_str),
+            Some("Bootstrap starting\n\n\n")
+        );
     }
 }
@@ -0,0 +1,178 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::ZoneRegistry;
+
+/// Presses of the same button on the same zone closer together than this
+/// count as a double click.
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Mouse event resolved to the topmost zone under the pointer, with
+/// coordinates relative to the zone's top-left cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneMouse {
+    pub zone_id: String,
+    pub local_row: u16,
+    pub local_col: u16,
+    pub kind: ZoneMouseKind,
+    pub modifiers: KeyModifiers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneMouseKind {
+    Click(MouseButton),
+    /// Second press within [`DOUBLE_CLICK_INTERVAL`]; replaces the click.
+    DoubleClick(MouseButton),
+    /// First movement with the button held, before any `DragMove`.
+    DragStart(MouseButton),
+    DragMove(MouseButton),
+    /// Button released after dragging. Releases without a drag report nothing.
+    DragEnd(MouseButton),
+    ScrollUp,
+    ScrollDown,
+}
+
+struct Press {
+    zone: String,
+    button: MouseButton,
+    dragging: bool,
+}
+
+/// Resolves raw mouse events into [`ZoneMouse`] events. Drags stay with the
+/// zone the button went down in, with coordinates clamped to its rect.
+#[derive(Default)]
+pub(crate) struct MouseTracker {
+    press: Option<Press>,
+    last_click: Option<(String, MouseButton, Instant)>,
+}
+
+impl MouseTracker {
+    pub(crate) fn resolve(
+        &mut self,
+        registry: &ZoneRegistry,
+        mouse: &MouseEvent,
+    ) -> Option<ZoneMouse> {
+        self.resolve_at(registry, mouse, Instant::now())
+    }
+
+    fn resolve_at(
+        &mut self,
+        registry: &ZoneRegistry,
+        mouse: &MouseEvent,
+        now: Instant,
+    ) -> Option<ZoneMouse> {
+        let (zone, rect, kind) = match mouse.kind {
+            MouseEventKind::Down(button) => {
+                self.press = None;
+                let (zone, rect) = registry.zone_at(mouse.column, mouse.row)?;
+                let double = self
+                    .last_click
+                    .as_ref()
+                    .is_some_and(|(last, last_button, at)| {
+                        *last == zone
+                            && *last_button == button
+                            && now.duration_since(*at) <= DOUBLE_CLICK_INTERVAL
+                    });
+                self.last_click = (!double).then(|| (zone.clone(), button, now));
+                self.press = Some(Press {
+                    zone: zone.clone(),
+                    button,
+                    dragging: false,
+                });
+                let kind = if double {
+                    ZoneMouseKind::DoubleClick(button)
+                } else {
+                    ZoneMouseKind::Click(button)
+                };
+                (zone, rect, kind)
+            }
+            MouseEventKind::Drag(button) => {
+                let press = self.press.as_mut().filter(|press| press.button == button)?;
+                let rect = registry.rect_of(&press.zone)?;
+                let kind = if press.dragging {
+                    ZoneMouseKind::DragMove(button)
+                } else {
+                    ZoneMouseKind::DragStart(button)
+                };
+                press.dragging = true;
+                (press.zone.clone(), rect, kind)
+            }
+            MouseEventKind::Up(button) => {
+                let press = self.press.take_if(|press| press.button == button)?;
+                if !press.dragging {
+                    return None;
+                }
+                let rect = registry.rect_of(&press.zone)?;
+                (press.zone, rect, ZoneMouseKind::DragEnd(button))
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let (zone, rect) = registry.zone_at(mouse.column, mouse.row)?;
+                let kind = if mouse.kind == MouseEventKind::ScrollUp {
+                    ZoneMouseKind::ScrollUp
+                } else {
+                    ZoneMouseKind::ScrollDown
+                };
+                (zone, rect, kind)
+            }
+            _ => return None,
+        };
+        Some(ZoneMouse {
+            zone_id: zone,
+            local_row: local(mouse.row, rect.y, rect.height),
+            local_col: local(mouse.column, rect.x, rect.width),
+            kind,
+            modifiers: mouse.modifiers,
+        })
+    }
+}
+
+fn local(position: u16, start: u16, length: u16) -> u16 {
+    position.saturating_sub(start).min(length.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rect;
+    use std::collections::HashMap;
+
+    fn registry() -> ZoneRegistry {
+        let mut registry = ZoneRegistry::new();
+        let mut solved = HashMap::new();
+        solved.insert("left".to_string(), Rect::new(0, 0, 5, 2));
+        solved.insert("right".to_string(), Rect::new(5, 0, 5, 2));
+        registry.sync_layout(&solved);
+        registry
+    }
+
+    fn event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn double_clicks_need_the_same_zone_and_interval() {
+        let registry = registry();
+        let mut tracker = MouseTracker::default();
+        let start = Instant::now();
+        let left = MouseButton::Left;
+        let mut press = |column, after: u64| {
+            let down = event(MouseEventKind::Down(left), column, 0);
+            let at = start + Duration::from_millis(after);
+            tracker
+                .resolve_at(&registry, &down, at)
+                .map(|mouse| mouse.kind)
+        };
+
+        assert_eq!(press(1, 0), Some(ZoneMouseKind::Click(left)));
+        assert_eq!(press(2, 100), Some(ZoneMouseKind::DoubleClick(left)));
+        assert_eq!(press(2, 200), Some(ZoneMouseKind::Click(left)));
+        assert_eq!(press(7, 300), Some(ZoneMouseKind::Click(left)));
+        assert_eq!(press(7, 1000), Some(ZoneMouseKind::Click(left)));
+    }
+}
@@ -6,6 +6,11 @@ use crate::{LayoutTree, Result, RoomRuntime};
 use super::{EventFlow, RuntimeContext, RuntimeEvent, shared_state};
 use crossterm::event::{KeyCode, KeyModifiers};
 
+/// Keymap action that cycles to the next screen, like `Ctrl+Tab`.
+pub const NEXT_SCREEN_ACTION: &str = "room::screen.next";
+/// Keymap action that cycles to the previous screen, like `Ctrl+Shift+Tab`.
+pub const PREVIOUS_SCREEN_ACTION: &str = "room::screen.previous";
+
 #[derive(Clone)]
 pub struct ScreenNavigator {
     relay: Arc<NavigationRelay>,
@@ -382,8 +387,24 @@ impl ScreenManager {
     }
 
     fn handle_navigation_hotkeys(&mut self, event: &RuntimeEvent) -> Result<Option<EventFlow>> {
-        let RuntimeEvent::Key(key) = event else {
-            return Ok(None);
+        let direction = match event {
+            RuntimeEvent::Key(key) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                match key.code {
+                    KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        CycleDirection::Backward
+                    }
+                    KeyCode::Tab => CycleDirection::Forward,
+                    KeyCode::BackTab => CycleDirection::Backward,
+                    _ => return Ok(None),
+                }
+            }
+            RuntimeEvent::Action(action) if action.is(NEXT_SCREEN_ACTION) => {
+                CycleDirection::Forward
+            }
+            RuntimeEvent::Action(action) if action.is(PREVIOUS_SCREEN_ACTION) => {
+                CycleDirection::Backward
+            }
+            _ => return Ok(None),
         };
 
         if self.ordered.len() < 2 {
@@ -394,28 +415,9 @@ impl ScreenManager {
             return Ok(None);
         };
 
-        if key.modifiers.contains(KeyModifiers::CONTROL) {
-            match key.code {
-                KeyCode::Tab => {
-                    let direction = if key.modifiers.contains(KeyModifiers::SHIFT) {
-                        CycleDirection::Backward
-                    } else {
-                        CycleDirection::Forward
-                    };
-                    if let Some(next_id) = self.next_screen_id(&active.id, direction) {
-                        self.request_activation(next_id)?;
-                        return Ok(Some(EventFlow::Consumed));
-                    }
-                }
-                KeyCode::BackTab => {
-                    if let Some(prev_id) = self.next_screen_id(&active.id, CycleDirection::Backward)
-                    {
-                        self.request_activation(prev_id)?;
-                        return Ok(Some(EventFlow::Consumed));
-                    }
-                }
-                _ => {}
-            }
+        if let Some(next_id) = self.next_screen_id(&active.id, direction) {
+            self.request_activation(next_id)?;
+            return Ok(Some(EventFlow::Consumed));
         }
 
         Ok(None)
@@ -768,7 +770,11 @@ mod tests {
         assert_eq!(manager.active_id(), Some("primary"));
 
         let event = RuntimeEvent::Key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
-        let mut ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let mut ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let flow = manager
             .handle_event(&mut ctx, &event)
             .expect("handle event");
@@ -822,7 +828,11 @@ mod tests {
         assert_eq!(manager.active_id(), Some("alpha"));
 
         // Ctrl+Tab → beta
-        let mut ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let mut ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let event = RuntimeEvent::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::CONTROL));
         let flow = manager.handle_event(&mut ctx, &event).expect("ctrl+tab");
         assert_eq!(flow, EventFlow::Consumed);
@@ -834,7 +844,11 @@ mod tests {
         assert_eq!(manager.active_id(), Some("beta"));
 
         // Ctrl+Tab again → gamma
-        let mut ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let mut ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let event = RuntimeEvent::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::CONTROL));
         manager
             .handle_event(&mut ctx, &event)
@@ -847,7 +861,11 @@ mod tests {
         assert_eq!(manager.active_id(), Some("gamma"));
 
         // Ctrl+Shift+Tab → beta
-        let mut ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let mut ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let event = RuntimeEvent::Key(KeyEvent::new(
             KeyCode::Tab,
             KeyModifiers::CONTROL | KeyModifiers::SHIFT,
@@ -865,7 +883,11 @@ mod tests {
         assert_eq!(manager.active_id(), Some("beta"));
 
         // Ctrl+BackTab → alpha
-        let mut ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let mut ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let event = RuntimeEvent::Key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::CONTROL));
         manager
             .handle_event(&mut ctx, &event)
@@ -990,7 +1012,11 @@ mod tests {
         // This verification is implicitly tested by the focus zone assertion below
 
         // Default focus should track the active screen
-        let ctx = RuntimeContext::new(&runtime.rects, &runtime.shared_state);
+        let ctx = RuntimeContext::new(
+            &runtime.rects,
+            &runtime.zone_constraints,
+            &runtime.shared_state,
+        );
         let focus_registry = ensure_focus_registry(&ctx).expect("focus registry");
         let focus_entry = focus_registry.current().expect("focus entry");
         assert_eq!(focus_entry.owner, RUNTIME_FOCUS_OWNER);
@@ -0,0 +1,394 @@
+use std::sync::{Arc, RwLock};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::{GridSize, LayoutEdit, Rect, Result};
+
+use super::{EventFlow, RoomPlugin, RuntimeContext, RuntimeEvent, ZoneResized};
+
+/// Direction panes are split in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitAxis {
+    /// Side-by-side panes, resized along the columns.
+    Columns,
+    /// Stacked panes, resized along the rows.
+    Rows,
+}
+
+/// Current position of a splitter, readable from outside the runtime so it
+/// can be persisted and passed back through [`SplitterPlugin::with_size`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SplitState {
+    /// Cells given to the leading pane.
+    pub size: u16,
+    /// Cells shared by both panes.
+    pub span: u16,
+}
+
+impl SplitState {
+    /// Share of the span taken by the leading pane, between 0.0 and 1.0.
+    pub fn ratio(&self) -> f32 {
+        if self.span == 0 {
+            0.0
+        } else {
+            self.size as f32 / self.span as f32
+        }
+    }
+}
+
+pub type SharedSplit = Arc<RwLock<SplitState>>;
+
+/// Resizable boundary between two grid panes.
+///
+/// The splitter owns the grid track of the leading pane and sets it to a
+/// `Fixed` size through [`RuntimeContext::edit_layout`]; the trailing pane
+/// should sit on a flexible track so it takes up the rest. The boundary is
+/// dragged with the left mouse button (grab the leading pane's last cell or
+/// the trailing pane's first) or nudged with the shrink/grow keys, which
+/// default to `Ctrl+Left`/`Ctrl+Right` for columns and `Ctrl+Up`/`Ctrl+Down`
+/// for rows. The size stays within `with_limits` and always leaves both panes
+/// at least one cell.
+pub struct SplitterPlugin {
+    axis: SplitAxis,
+    track: usize,
+    leading: String,
+    trailing: String,
+    min: u16,
+    max: u16,
+    step: u16,
+    shrink: KeyEvent,
+    grow: KeyEvent,
+    initial: Option<u16>,
+    dragging: bool,
+    state: SharedSplit,
+}
+
+impl SplitterPlugin {
+    /// Split grid column `track`, holding `leading`, from `trailing` to its right.
+    pub fn columns(track: usize, leading: impl Into<String>, trailing: impl Into<String>) -> Self {
+        Self::new(SplitAxis::Columns, track, leading.into(), trailing.into())
+    }
+
+    /// Split grid row `track`, holding `leading`, from `trailing` below it.
+    pub fn rows(track: usize, leading: impl Into<String>, trailing: impl Into<String>) -> Self {
+        Self::new(SplitAxis::Rows, track, leading.into(), trailing.into())
+    }
+
+    fn new(axis: SplitAxis, track: usize, leading: String, trailing: String) -> Self {
+        let (shrink, grow) = match axis {
+            SplitAxis::Columns => (KeyCode::Left, KeyCode::Right),
+            SplitAxis::Rows => (KeyCode::Up, KeyCode::Down),
+        };
+        Self {
+            axis,
+            track,
+            leading,
+            trailing,
+            min: 1,
+            max: u16::MAX,
+            step: 1,
+            shrink: KeyEvent::new(shrink, KeyModifiers::CONTROL),
+            grow: KeyEvent::new(grow, KeyModifiers::CONTROL),
+            initial: None,
+            dragging: false,
+            state: Arc::new(RwLock::new(SplitState::default())),
+        }
+    }
+
+    pub fn with_limits(mut self, min: u16, max: u16) -> Self {
+        self.min = min;
+        self.max = max.max(min);
+        self
+    }
+
+    pub fn with_step(mut self, step: u16) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    pub fn with_keys(mut self, shrink: KeyEvent, grow: KeyEvent) -> Self {
+        self.shrink = shrink;
+        self.grow = grow;
+        self
+    }
+
+    /// Start from a saved size instead of the one the layout solved.
+    pub fn with_size(mut self, size: u16) -> Self {
+        self.initial = Some(size);
+        self
+    }
+
+    /// Live splitter position, updated whenever the boundary moves.
+    pub fn state(&self) -> SharedSplit {
+        Arc::clone(&self.state)
+    }
+
+    fn extent(&self, rect: &Rect) -> (u16, u16) {
+        match self.axis {
+            SplitAxis::Columns => (rect.x, rect.width),
+            SplitAxis::Rows => (rect.y, rect.height),
+        }
+    }
+
+    fn panes(&self, ctx: &RuntimeContext<'_>) -> Option<(Rect, Rect)> {
+        Some((*ctx.rect(&self.leading)?, *ctx.rect(&self.trailing)?))
+    }
+
+    fn resize(&mut self, ctx: &mut RuntimeContext<'_>, size: u16) {
+        let Some((leading, trailing)) = self.panes(ctx) else {
+            return;
+        };
+        let span = self.extent(&leading).1 + self.extent(&trailing).1;
+        let size = size
+            .clamp(self.min, self.max)
+            .min(span.saturating_sub(1))
+            .max(1);
+        *self.state.write().unwrap() = SplitState { size, span };
+        if size == self.extent(&leading).1 {
+            return;
+        }
+        let edit = match self.axis {
+            SplitAxis::Columns => LayoutEdit::SetCol {
+                index: self.track,
+                size: GridSize::Fixed(size),
+            },
+            SplitAxis::Rows => LayoutEdit::SetRow {
+                index: self.track,
+                size: GridSize::Fixed(size),
+            },
+        };
+        ctx.edit_layout(edit);
+    }
+
+    fn handle_key(&mut self, ctx: &mut RuntimeContext<'_>, key: &KeyEvent) -> EventFlow {
+        let delta = if key.code == self.shrink.code && key.modifiers == self.shrink.modifiers {
+            -(self.step as i32)
+        } else if key.code == self.grow.code && key.modifiers == self.grow.modifiers {
+            self.step as i32
+        } else {
+            return EventFlow::Continue;
+        };
+        let Some((leading, _)) = self.panes(ctx) else {
+            return EventFlow::Continue;
+        };
+        let current = self.extent(&leading).1 as i32;
+        let size = (current + delta).clamp(0, u16::MAX as i32) as u16;
+        self.resize(ctx, size);
+        EventFlow::Consumed
+    }
+
+    fn handle_mouse(&mut self, ctx: &mut RuntimeContext<'_>, mouse: &MouseEvent) -> EventFlow {
+        let Some((leading, _)) = self.panes(ctx) else {
+            return EventFlow::Continue;
+        };
+        let (along, across) = match self.axis {
+            SplitAxis::Columns => (mouse.column, mouse.row),
+            SplitAxis::Rows => (mouse.row, mouse.column),
+        };
+        let (start, length) = self.extent(&leading);
+        let boundary = start + length;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let (cross_start, cross_length) = match self.axis {
+                    SplitAxis::Columns => (leading.y, leading.height),
+                    SplitAxis::Rows => (leading.x, leading.width),
+                };
+                let on_boundary = along + 1 == boundary || along == boundary;
+                let beside = across >= cross_start && across < cross_start + cross_length;
+                if on_boundary && beside {
+                    self.dragging = true;
+                    return EventFlow::Consumed;
+                }
+                EventFlow::Continue
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                self.resize(ctx, along.saturating_sub(start) + 1);
+                EventFlow::Consumed
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                EventFlow::Consumed
+            }
+            _ => EventFlow::Continue,
+        }
+    }
+}
+
+impl RoomPlugin for SplitterPlugin {
+    fn name(&self) -> &str {
+        "room::splitter"
+    }
+
+    fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+        let Some((leading, _)) = self.panes(ctx) else {
+            return Ok(());
+        };
+        let size = self.initial.unwrap_or(self.extent(&leading).1);
+        self.resize(ctx, size);
+        Ok(())
+    }
+
+    fn on_event(
+        &mut self,
+        ctx: &mut RuntimeContext<'_>,
+        event: &RuntimeEvent,
+    ) -> Result<EventFlow> {
+        Ok(match event {
+            RuntimeEvent::Key(key) => self.handle_key(ctx, key),
+            RuntimeEvent::Mouse(mouse) => self.handle_mouse(ctx, mouse),
+            _ => EventFlow::Continue,
+        })
+    }
+
+    fn on_zone_resized(
+        &mut self,
+        ctx: &mut RuntimeContext<'_>,
+        resized: &ZoneResized,
+    ) -> Result<()> {
+        if resized.zone != self.leading && resized.zone != self.trailing {
+            return Ok(());
+        }
+        if let Some((leading, trailing)) = self.panes(ctx) {
+            *self.state.write().unwrap() = SplitState {
+                size: self.extent(&leading).1,
+                span: self.extent(&leading).1 + self.extent(&trailing).1,
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnsiRenderer, GridArea, GridLayout, RoomRuntime, Size, VirtualTerminal};
+
+    struct Panes;
+
+    impl RoomPlugin for Panes {
+        fn name(&self) -> &str {
+            "panes"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone("side", "Side");
+            ctx.set_zone("main", "Main");
+            Ok(())
+        }
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16) -> RuntimeEvent {
+        RuntimeEvent::Mouse(MouseEvent {
+            kind,
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn panes_runtime() -> RoomRuntime {
+        let mut grid = GridLayout::new();
+        grid.add_col(GridSize::Fixed(6))
+            .add_col(GridSize::flex(1))
+            .add_row(GridSize::flex(1));
+        grid.place("side", GridArea::cell(0, 0)).unwrap();
+        grid.place("main", GridArea::cell(0, 1)).unwrap();
+
+        let mut runtime =
+            RoomRuntime::new(grid, AnsiRenderer::with_default(), Size::new(20, 1)).unwrap();
+        runtime.register_plugin(Panes);
+        runtime
+    }
+
+    /// Records every frame write separately while mirroring it to a terminal.
+    struct FrameWrites {
+        terminal: VirtualTerminal,
+        writes: Vec<String>,
+    }
+
+    impl std::io::Write for FrameWrites {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes.push(String::from_utf8_lossy(buf).into_owned());
+            self.terminal.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.terminal.flush()
+        }
+    }
+
+    #[test]
+    fn keys_and_drags_move_the_boundary_within_limits() {
+        let mut runtime = panes_runtime();
+        let splitter = SplitterPlugin::columns(0, "side", "main").with_limits(4, 12);
+        let state = splitter.state();
+        runtime.register_plugin(splitter);
+
+        let grow = || RuntimeEvent::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL));
+        let left = MouseButton::Left;
+        let mut terminal = VirtualTerminal::new(Size::new(20, 1));
+        runtime
+            .run_scripted(
+                &mut terminal,
+                [
+                    grow(),
+                    grow(),
+                    mouse(MouseEventKind::Down(left), 7),
+                    mouse(MouseEventKind::Drag(left), 9),
+                    mouse(MouseEventKind::Drag(left), 15),
+                    mouse(MouseEventKind::Up(left), 15),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            terminal.frames(),
+            &[
+                "Side  Main",
+                "Side   Main",
+                "Side    Main",
+                "Side      Main",
+                "Side        Main",
+            ]
+        );
+        let state = *state.read().unwrap();
+        assert_eq!(state, SplitState { size: 12, span: 20 });
+        assert_eq!(state.ratio(), 0.6);
+    }
+
+    #[test]
+    fn drag_steps_repaint_only_the_moved_panes() {
+        let mut runtime = panes_runtime();
+        runtime.register_plugin(SplitterPlugin::columns(0, "side", "main"));
+
+        let left = MouseButton::Left;
+        let mut output = FrameWrites {
+            terminal: VirtualTerminal::new(Size::new(20, 1)),
+            writes: Vec::new(),
+        };
+        runtime
+            .run_scripted(
+                &mut output,
+                [
+                    mouse(MouseEventKind::Down(left), 5),
+                    mouse(MouseEventKind::Drag(left), 6),
+                    mouse(MouseEventKind::Drag(left), 4),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            output.terminal.frames(),
+            &["Side  Main", "Side   Main", "Side Main"]
+        );
+        let steps = &output.writes[output.writes.len() - 2..];
+        for step in steps {
+            assert!(
+                !step.contains("\x1b[2J"),
+                "drag step cleared the screen: {step:?}"
+            );
+        }
+        assert_eq!(steps[0], "\x1b[1;7H Main");
+        assert_eq!(steps[1], "\x1b[1;6HMain  ");
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Shortest period accepted by [`RuntimeContext::schedule_every`](super::RuntimeContext::schedule_every).
+pub const MIN_TIMER_PERIOD: Duration = Duration::from_millis(1);
+
+static NEXT_TIMER: AtomicU64 = AtomicU64::new(1);
+
+/// Handle returned when a timer is scheduled; pass it to
+/// [`RuntimeContext::cancel_timer`](super::RuntimeContext::cancel_timer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerHandle(u64);
+
+impl TimerHandle {
+    pub(crate) fn next() -> Self {
+        Self(NEXT_TIMER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+pub(crate) enum TimerRequest {
+    Schedule {
+        handle: TimerHandle,
+        token: String,
+        delay: Duration,
+        every: Option<Duration>,
+    },
+    Cancel(TimerHandle),
+}
+
+struct Timer {
+    handle: TimerHandle,
+    token: String,
+    due: Duration,
+    every: Option<Duration>,
+}
+
+/// Pending timers on the runtime clock, which only moves forward through
+/// [`TimerQueue::advance`].
+#[derive(Default)]
+pub(crate) struct TimerQueue {
+    now: Duration,
+    timers: Vec<Timer>,
+}
+
+impl TimerQueue {
+    pub(crate) fn apply(&mut self, request: TimerRequest) {
+        match request {
+            TimerRequest::Schedule {
+                handle,
+                token,
+                delay,
+                every,
+            } => self.timers.push(Timer {
+                handle,
+                token,
+                due: self.now + delay,
+                every,
+            }),
+            TimerRequest::Cancel(handle) => self.timers.retain(|timer| timer.handle != handle),
+        }
+    }
+
+    /// Time left until the earliest timer is due.
+    pub(crate) fn next_due(&self) -> Option<Duration> {
+        self.timers
+            .iter()
+            .map(|timer| timer.due.saturating_sub(self.now))
+            .min()
+    }
+
+    /// Move the clock forward and drain the timers that came due, earliest
+    /// first. Repeating timers fire at most once per call and skip the
+    /// periods they fell behind on.
+    pub(crate) fn advance(&mut self, elapsed: Duration) -> Vec<(TimerHandle, String)> {
+        self.now += elapsed;
+        let now = self.now;
+        let mut due: Vec<(Duration, TimerHandle, String)> = Vec::new();
+        self.timers.retain_mut(|timer| {
+            if timer.due > now {
+                return true;
+            }
+            due.push((timer.due, timer.handle, timer.token.clone()));
+            match timer.every {
+                Some(period) => {
+                    timer.due += period;
+                    if timer.due <= now {
+                        timer.due = now + period;
+                    }
+                    true
+                }
+                None => false,
+            }
+        });
+        due.sort_by_key(|(at, _, _)| *at);
+        due.into_iter()
+            .map(|(_, handle, token)| (handle, token))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(queue: &mut TimerQueue, token: &str, ms: u64, every: bool) -> TimerHandle {
+        let handle = TimerHandle::next();
+        let delay = Duration::from_millis(ms);
+        queue.apply(TimerRequest::Schedule {
+            handle,
+            token: token.to_string(),
+            delay,
+            every: every.then_some(delay),
+        });
+        handle
+    }
+
+    fn tokens(fired: Vec<(TimerHandle, String)>) -> Vec<String> {
+        fired.into_iter().map(|(_, token)| token).collect()
+    }
+
+    #[test]
+    fn timers_fire_in_order_and_repeat_until_cancelled() {
+        let mut queue = TimerQueue::default();
+        let spinner = schedule(&mut queue, "spin", 100, true);
+        schedule(&mut queue, "toast", 250, false);
+        assert_eq!(queue.next_due(), Some(Duration::from_millis(100)));
+
+        let ms = Duration::from_millis;
+        assert_eq!(tokens(queue.advance(ms(90))), Vec::<String>::new());
+        assert_eq!(tokens(queue.advance(ms(10))), vec!["spin"]);
+        assert_eq!(tokens(queue.advance(ms(200))), vec!["spin", "toast"]);
+        assert_eq!(queue.next_due(), Some(ms(100)));
+
+        queue.apply(TimerRequest::Cancel(spinner));
+        assert_eq!(queue.next_due(), None);
+        assert!(queue.advance(ms(1000)).is_empty());
+    }
+}
@@ -0,0 +1,480 @@
+/// Terminal colour understood by the renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// 256-colour palette index.
+    Indexed(u8),
+    /// 24-bit colour.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn from_ansi_index(index: u8) -> Self {
+        match index {
+            0 => Color::Black,
+            1 => Color::Red,
+            2 => Color::Green,
+            3 => Color::Yellow,
+            4 => Color::Blue,
+            5 => Color::Magenta,
+            6 => Color::Cyan,
+            7 => Color::White,
+            8 => Color::BrightBlack,
+            9 => Color::BrightRed,
+            10 => Color::BrightGreen,
+            11 => Color::BrightYellow,
+            12 => Color::BrightBlue,
+            13 => Color::BrightMagenta,
+            14 => Color::BrightCyan,
+            15 => Color::BrightWhite,
+            other => Color::Indexed(other),
+        }
+    }
+
+    fn ansi_index(&self) -> Option<u8> {
+        match self {
+            Color::Black => Some(0),
+            Color::Red => Some(1),
+            Color::Green => Some(2),
+            Color::Yellow => Some(3),
+            Color::Blue => Some(4),
+            Color::Magenta => Some(5),
+            Color::Cyan => Some(6),
+            Color::White => Some(7),
+            Color::BrightBlack => Some(8),
+            Color::BrightRed => Some(9),
+            Color::BrightGreen => Some(10),
+            Color::BrightYellow => Some(11),
+            Color::BrightBlue => Some(12),
+            Color::BrightMagenta => Some(13),
+            Color::BrightCyan => Some(14),
+            Color::BrightWhite => Some(15),
+            Color::Indexed(_) | Color::Rgb(..) => None,
+        }
+    }
+
+    /// SGR parameters selecting this colour; `base` is 30 for foreground and 40
+    /// for background.
+    fn sgr_params(&self, base: u8) -> String {
+        match (self, self.ansi_index()) {
+            (_, Some(index)) if index < 8 => format!("{}", base + index),
+            (_, Some(index)) => format!("{}", base + 60 + index - 8),
+            (Color::Indexed(index), None) => format!("{};5;{}", base + 8, index),
+            (Color::Rgb(r, g, b), None) => format!("{};2;{};{};{}", base + 8, r, g, b),
+            _ => unreachable!("named colours always map to an ANSI index"),
+        }
+    }
+}
+
+/// Visual attributes applied to a run of text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub reverse: bool,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            reverse: false,
+        }
+    }
+
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Style::default()
+    }
+
+    /// SGR sequence that switches a terminal from the default style to this one.
+    /// Returns an empty string for the default style.
+    pub fn to_sgr(&self) -> String {
+        let mut params: Vec<String> = Vec::new();
+        if self.bold {
+            params.push("1".into());
+        }
+        if self.dim {
+            params.push("2".into());
+        }
+        if self.italic {
+            params.push("3".into());
+        }
+        if self.underline {
+            params.push("4".into());
+        }
+        if self.reverse {
+            params.push("7".into());
+        }
+        if let Some(fg) = self.fg {
+            params.push(fg.sgr_params(30));
+        }
+        if let Some(bg) = self.bg {
+            params.push(bg.sgr_params(40));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", params.join(";"))
+        }
+    }
+
+    /// Fold the parameters of an SGR sequence (the part between `ESC[` and `m`)
+    /// into this style. Empty parameters mean `0`; unsupported attributes and
+    /// malformed parameters are ignored.
+    pub fn apply_sgr(&mut self, params: &str) {
+        // Malformed parameters stay in place as `None` so the arguments of
+        // extended colours keep their positions.
+        let codes: Vec<Option<u16>> = params.split([';', ':']).map(parse_sgr_param).collect();
+
+        let mut idx = 0;
+        while idx < codes.len() {
+            let Some(code) = codes[idx] else {
+                idx += 1;
+                continue;
+            };
+            match code {
+                0 => *self = Style::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.reverse = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.reverse = false,
+                code @ 30..=37 => self.fg = Some(Color::from_ansi_index((code - 30) as u8)),
+                39 => self.fg = None,
+                code @ 40..=47 => self.bg = Some(Color::from_ansi_index((code - 40) as u8)),
+                49 => self.bg = None,
+                code @ 90..=97 => self.fg = Some(Color::from_ansi_index((code - 90 + 8) as u8)),
+                code @ 100..=107 => self.bg = Some(Color::from_ansi_index((code - 100 + 8) as u8)),
+                code @ (38 | 48) => {
+                    let (color, consumed) = parse_extended_color(&codes[idx + 1..]);
+                    if let Some(color) = color {
+                        if code == 38 {
+                            self.fg = Some(color);
+                        } else {
+                            self.bg = Some(color);
+                        }
+                    }
+                    idx += consumed;
+                }
+                _ => {}
+            }
+            idx += 1;
+        }
+    }
+}
+
+/// An SGR parameter: empty means 0 (ECMA-48), digits saturate, and anything
+/// else is malformed.
+fn parse_sgr_param(param: &str) -> Option<u16> {
+    if !param.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    Some(param.bytes().fold(0u16, |value, digit| {
+        value
+            .saturating_mul(10)
+            .saturating_add(u16::from(digit - b'0'))
+    }))
+}
+
+fn parse_extended_color(codes: &[Option<u16>]) -> (Option<Color>, usize) {
+    let channel = |value: &Option<u16>| value.map(|value| value.min(255) as u8);
+    match codes {
+        [Some(5), index, ..] => (channel(index).map(Color::from_ansi_index), 2),
+        [Some(2), r, g, b, ..] => {
+            let color = match (channel(r), channel(g), channel(b)) {
+                (Some(r), Some(g), Some(b)) => Some(Color::Rgb(r, g, b)),
+                _ => None,
+            };
+            (color, 4)
+        }
+        [Some(5)] | [Some(2), ..] => (None, codes.len()),
+        _ => (None, 0),
+    }
+}
+
+/// Run of text sharing one style.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: Style,
+}
+
+impl StyledSpan {
+    pub fn new(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::new(text, Style::default())
+    }
+}
+
+/// Styled zone payload: an ordered list of spans. Newlines inside span text
+/// separate lines, exactly like plain zone content.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StyledText {
+    spans: Vec<StyledSpan>,
+}
+
+impl StyledText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a span, returning `self` for chaining.
+    pub fn span(mut self, text: impl Into<String>, style: Style) -> Self {
+        self.push(text, style);
+        self
+    }
+
+    /// Append a span, merging it into the previous one when the styles match.
+    pub fn push(&mut self, text: impl Into<String>, style: Style) {
+        let text = text.into();
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => self.spans.push(StyledSpan::new(text, style)),
+        }
+    }
+
+    pub fn spans(&self) -> &[StyledSpan] {
+        &self.spans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Concatenated text with styles dropped.
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Parse text containing SGR escape sequences into spans. Other escape
+    /// sequences are dropped.
+    pub fn from_ansi(text: &str) -> Self {
+        let mut style = Style::default();
+        let mut result = StyledText::new();
+        let mut current = String::new();
+        let mut chars = text.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch != '\x1b' {
+                current.push(ch);
+                continue;
+            }
+
+            if chars.peek() != Some(&'[') {
+                continue;
+            }
+            chars.next();
+
+            let mut params = String::new();
+            let mut final_byte = None;
+            for next in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&next) {
+                    final_byte = Some(next);
+                    break;
+                }
+                params.push(next);
+            }
+
+            if final_byte == Some('m') {
+                result.push(std::mem::take(&mut current), style);
+                style.apply_sgr(&params);
+            }
+        }
+
+        result.push(current, style);
+        result
+    }
+
+    /// Split into lines on `\n`, keeping each span's style.
+    pub fn lines(&self) -> Vec<Vec<StyledSpan>> {
+        let mut lines = vec![Vec::new()];
+        for span in &self.spans {
+            for (idx, part) in span.text.split('\n').enumerate() {
+                if idx > 0 {
+                    lines.push(Vec::new());
+                }
+                if !part.is_empty() {
+                    lines
+                        .last_mut()
+                        .expect("at least one line")
+                        .push(StyledSpan::new(part, span.style));
+                }
+            }
+        }
+        lines
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(text: &str) -> Self {
+        StyledText::new().span(text, Style::default())
+    }
+}
+
+impl From<String> for StyledText {
+    fn from(text: String) -> Self {
+        StyledText::new().span(text, Style::default())
+    }
+}
+
+impl From<StyledSpan> for StyledText {
+    fn from(span: StyledSpan) -> Self {
+        StyledText::new().span(span.text, span.style)
+    }
+}
+
+impl From<Vec<StyledSpan>> for StyledText {
+    fn from(spans: Vec<StyledSpan>) -> Self {
+        let mut text = StyledText::new();
+        for span in spans {
+            text.push(span.text, span.style);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sgr_round_trip() {
+        let style = Style::new().fg(Color::Red).bg(Color::Rgb(1, 2, 3)).bold();
+        let sgr = style.to_sgr();
+        assert_eq!(sgr, "\x1b[1;31;48;2;1;2;3m");
+
+        let mut parsed = Style::default();
+        parsed.apply_sgr(&sgr[2..sgr.len() - 1]);
+        assert_eq!(parsed, style);
+    }
+
+    #[test]
+    fn malformed_sgr_parameters_are_skipped() {
+        let mut style = Style::new().bold();
+        style.apply_sgr("x;31");
+        assert_eq!(style, Style::new().bold().fg(Color::Red));
+
+        style.apply_sgr("");
+        assert_eq!(style, Style::default());
+
+        // Empty colour arguments are 0 and keep the others in place.
+        style.apply_sgr("38;2;;128;255");
+        assert_eq!(style, Style::new().fg(Color::Rgb(0, 128, 255)));
+        style.apply_sgr("48;2;300;99999;7;1");
+        assert_eq!(
+            style,
+            Style::new()
+                .fg(Color::Rgb(0, 128, 255))
+                .bg(Color::Rgb(255, 255, 7))
+                .bold()
+        );
+        style.apply_sgr("38;5;x;4");
+        assert_eq!(style.fg, Some(Color::Rgb(0, 128, 255)));
+        assert!(style.underline);
+    }
+
+    #[test]
+    fn from_ansi_splits_spans_and_drops_other_escapes() {
+        let text = StyledText::from_ansi("a\x1b[31mb\x1b[2Kc\x1b[0md");
+        let red = Style::new().fg(Color::Red);
+        assert_eq!(
+            text.spans(),
+            &[
+                StyledSpan::plain("a"),
+                StyledSpan::new("bc", red),
+                StyledSpan::plain("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_keep_styles() {
+        let bold = Style::new().bold();
+        let text = StyledText::new()
+            .span("one\ntwo", bold)
+            .span("!", Style::default());
+        let lines = text.lines();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], vec![StyledSpan::new("one", bold)]);
+        assert_eq!(
+            lines[1],
+            vec![StyledSpan::new("two", bold), StyledSpan::plain("!")]
+        );
+    }
+}
@@ -0,0 +1,64 @@
+/// How a zone handles text that does not fit its width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Break lines at word boundaries, falling back to character breaks for
+    /// words longer than the zone.
+    WordWrap,
+    /// Break lines at the last character that fits.
+    #[default]
+    CharWrap,
+    /// Keep each line on one row and end it with `…` when it is cut.
+    Truncate,
+    /// Keep each line on one row and cut it at the zone edge.
+    Clip,
+}
+
+/// Horizontal placement of each line inside a zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HorizontalAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical placement of the content block inside a zone.
+///
+/// When the content is taller than the zone, `Bottom` keeps the last lines
+/// visible while the other variants keep the first ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Per-zone text presentation settings honoured by the renderer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ZoneFormat {
+    pub overflow: Overflow,
+    pub align: HorizontalAlign,
+    pub vertical_align: VerticalAlign,
+}
+
+impl ZoneFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn align(mut self, align: HorizontalAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.vertical_align = align;
+        self
+    }
+}
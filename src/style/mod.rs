@@ -0,0 +1,8 @@
+//! Style module orchestrator: colours, text attributes, styled spans, and
+//! per-zone text formatting.
+
+mod core;
+mod format;
+
+pub use core::{Color, Style, StyledSpan, StyledText};
+pub use format::{HorizontalAlign, Overflow, VerticalAlign, ZoneFormat};
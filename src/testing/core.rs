@@ -0,0 +1,297 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::error::LayoutError;
+use crate::geometry::Size;
+use crate::headless::VirtualTerminal;
+use crate::runtime::{RoomRuntime, RuntimeEvent};
+
+/// Environment variable that rewrites golden files instead of comparing them.
+pub const SNAPSHOT_BLESS_ENV: &str = "ROOM_SNAPSHOT_BLESS";
+
+const FRAME_HEADER: &str = "--- frame ";
+
+/// Errors raised while running or comparing snapshots.
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("runtime failed during scripted run: {0}")]
+    Runtime(#[from] LayoutError),
+    #[error("snapshot io error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("snapshot `{path}` does not exist; rerun with {SNAPSHOT_BLESS_ENV}=1 to create it")]
+    Missing { path: PathBuf },
+    #[error(
+        "snapshot `{path}` differs at frame {frame}\n--- expected ---\n{expected}\n--- actual ---\n{actual}\nrerun with {SNAPSHOT_BLESS_ENV}=1 to accept the new output"
+    )]
+    Mismatch {
+        path: PathBuf,
+        frame: usize,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Screen text captured from a scripted run, one entry per committed frame.
+#[derive(Debug, Clone)]
+pub struct ScriptedRun {
+    frames: Vec<String>,
+    terminal: VirtualTerminal,
+}
+
+impl ScriptedRun {
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Screen text after the last frame.
+    pub fn final_screen(&self) -> String {
+        self.terminal.screen_text()
+    }
+
+    /// Terminal state after the run, for cell- or style-level assertions.
+    pub fn terminal(&self) -> &VirtualTerminal {
+        &self.terminal
+    }
+
+    /// Serialise the frames into the golden file format.
+    pub fn to_snapshot(&self) -> String {
+        let mut out = String::new();
+        for (idx, frame) in self.frames.iter().enumerate() {
+            let _ = writeln!(out, "{FRAME_HEADER}{} ---", idx + 1);
+            out.push_str(frame);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Run `runtime` at a fixed `size` against a scripted event sequence,
+/// capturing the screen text after every render.
+pub fn run_script<I>(
+    runtime: &mut RoomRuntime,
+    size: Size,
+    events: I,
+) -> Result<ScriptedRun, SnapshotError>
+where
+    I: IntoIterator<Item = RuntimeEvent>,
+{
+    runtime.resize(size)?;
+    let mut terminal = VirtualTerminal::new(size);
+    runtime.run_scripted(&mut terminal, events)?;
+    let frames = terminal.take_frames();
+    Ok(ScriptedRun { frames, terminal })
+}
+
+/// Golden-file snapshot stored as `<dir>/<name>.snap`.
+///
+/// The default directory is `tests/snapshots` under the crate being tested
+/// (`CARGO_MANIFEST_DIR`). Setting [`SNAPSHOT_BLESS_ENV`] writes the current
+/// output instead of comparing against it.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    name: String,
+    dir: PathBuf,
+}
+
+impl Snapshot {
+    pub fn new(name: impl Into<String>) -> Self {
+        let root = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."));
+        Self {
+            name: name.into(),
+            dir: root.join("tests").join("snapshots"),
+        }
+    }
+
+    pub fn in_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(format!("{}.snap", self.name))
+    }
+
+    /// Compare a run against the golden file, or rewrite it when blessing.
+    pub fn check(&self, run: &ScriptedRun) -> Result<(), SnapshotError> {
+        let path = self.path();
+        let actual = run.to_snapshot();
+
+        if bless_requested() {
+            std::fs::create_dir_all(&self.dir).map_err(|source| SnapshotError::Io {
+                path: self.dir.clone(),
+                source,
+            })?;
+            return std::fs::write(&path, actual)
+                .map_err(|source| SnapshotError::Io { path, source });
+        }
+
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(SnapshotError::Missing { path });
+            }
+            Err(source) => return Err(SnapshotError::Io { path, source }),
+        };
+
+        if expected == actual {
+            return Ok(());
+        }
+
+        let expected_frames = split_frames(&expected);
+        let actual_frames = split_frames(&actual);
+        let frame = expected_frames
+            .iter()
+            .zip(&actual_frames)
+            .position(|(a, b)| a != b)
+            .unwrap_or(expected_frames.len().min(actual_frames.len()));
+        Err(SnapshotError::Mismatch {
+            path,
+            frame: frame + 1,
+            expected: expected_frames.get(frame).cloned().unwrap_or_default(),
+            actual: actual_frames.get(frame).cloned().unwrap_or_default(),
+        })
+    }
+
+    /// Like [`Snapshot::check`], panicking with a readable diff on failure.
+    #[track_caller]
+    pub fn assert(&self, run: &ScriptedRun) {
+        if let Err(err) = self.check(run) {
+            panic!("{err}");
+        }
+    }
+}
+
+fn bless_requested() -> bool {
+    std::env::var(SNAPSHOT_BLESS_ENV)
+        .map(|value| !value.is_empty() && value != "0")
+        .unwrap_or(false)
+}
+
+fn split_frames(snapshot: &str) -> Vec<String> {
+    let mut frames: Vec<Vec<&str>> = Vec::new();
+    for line in snapshot.lines() {
+        if line.starts_with(FRAME_HEADER) {
+            frames.push(Vec::new());
+        } else if let Some(frame) = frames.last_mut() {
+            frame.push(line);
+        }
+    }
+    frames.into_iter().map(|lines| lines.join("\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AnsiRenderer, Constraint, Direction, EventFlow, LayoutNode, LayoutTree, Result,
+        RoomPlugin, RuntimeContext,
+    };
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    struct CounterPlugin {
+        presses: usize,
+    }
+
+    impl RoomPlugin for CounterPlugin {
+        fn name(&self) -> &str {
+            "counter"
+        }
+
+        fn init(&mut self, ctx: &mut RuntimeContext<'_>) -> Result<()> {
+            ctx.set_zone("app:body", "presses: 0");
+            Ok(())
+        }
+
+        fn on_event(
+            &mut self,
+            ctx: &mut RuntimeContext<'_>,
+            event: &RuntimeEvent,
+        ) -> Result<EventFlow> {
+            if let RuntimeEvent::Key(_) = event {
+                self.presses += 1;
+                ctx.set_zone("app:body", format!("presses: {}", self.presses));
+            }
+            Ok(EventFlow::Continue)
+        }
+    }
+
+    fn counter_runtime() -> RoomRuntime {
+        let layout = LayoutTree::new(LayoutNode {
+            id: "app:root".into(),
+            direction: Direction::Column,
+            constraints: vec![Constraint::Fixed(1), Constraint::Flex(1)],
+            children: vec![LayoutNode::leaf("app:title"), LayoutNode::leaf("app:body")],
+            gap: 0,
+            padding: 0,
+        });
+        let mut runtime =
+            RoomRuntime::new(layout, AnsiRenderer::with_default(), Size::new(10, 3)).unwrap();
+        runtime.register_plugin(CounterPlugin { presses: 0 });
+        runtime
+    }
+
+    fn key() -> RuntimeEvent {
+        RuntimeEvent::Key(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn run_script_captures_each_frame() {
+        let mut runtime = counter_runtime();
+        let run = run_script(&mut runtime, Size::new(12, 2), [key(), key()]).unwrap();
+
+        assert_eq!(
+            run.frames(),
+            &[
+                "\npresses: 0".to_string(),
+                "\npresses: 1".to_string(),
+                "\npresses: 2".to_string(),
+            ]
+        );
+        assert_eq!(run.final_screen(), "\npresses: 2");
+    }
+
+    #[test]
+    fn golden_snapshot_matches() {
+        let mut runtime = counter_runtime();
+        let run = run_script(&mut runtime, Size::new(12, 2), [key()]).unwrap();
+        Snapshot::new("testing_counter").assert(&run);
+    }
+
+    #[test]
+    fn mismatch_reports_first_differing_frame() {
+        let dir = std::env::temp_dir().join(format!("room-snapshots-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("counter.snap"),
+            "--- frame 1 ---\n\npresses: 0\n--- frame 2 ---\n\npresses: 9\n",
+        )
+        .unwrap();
+
+        let mut runtime = counter_runtime();
+        let run = run_script(&mut runtime, Size::new(12, 2), [key()]).unwrap();
+        let err = Snapshot::new("counter").in_dir(&dir).check(&run).unwrap_err();
+        std::fs::remove_dir_all(&dir).ok();
+
+        match err {
+            SnapshotError::Mismatch {
+                frame,
+                expected,
+                actual,
+                ..
+            } => {
+                assert_eq!(frame, 2);
+                assert_eq!(expected, "\npresses: 9");
+                assert_eq!(actual, "\npresses: 1");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! Snapshot testing orchestrator: scripted runs against a headless terminal
+//! compared with golden files.
+
+mod core;
+
+pub use core::{SNAPSHOT_BLESS_ENV, ScriptedRun, Snapshot, SnapshotError, run_script};